@@ -0,0 +1,261 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use common::csv;
+use common::json::{self, Json};
+use common::params::Params;
+use crate::solve;
+
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+const REPORT_MARKER: &str = "--- bench report ---";
+
+/// Wraps the system allocator to count allocations and the peak number of bytes allocated,
+/// which only takes effect in a binary which declares it as the #[global_allocator]
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn allocated(size: usize) {
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// The cost of solving one part of a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub day: usize,
+    pub part: usize,
+    pub input: String,
+    pub millis: f64,
+    pub peak_bytes: usize, // above what was allocated before solving
+    pub allocations: usize,
+    pub result: Result<String, String> // the answer, or why there isn't one
+}
+
+/// Solves one part of a day in this process, timing it and counting its allocations
+pub fn measure(day: usize, part: usize, input: &str, text: &str, params: &Params) -> Measurement {
+    let baseline = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(baseline, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let result = solve(day, part, text, params);
+    let millis = start.elapsed().as_secs_f64() * 1000.0;
+    Measurement {
+        day,
+        part,
+        input: input.to_string(),
+        millis,
+        peak_bytes: PEAK_BYTES.load(Ordering::Relaxed) - baseline,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        result: match result {
+            Ok(answer) => Ok(answer.to_string()),
+            Err(error) => Err(error.in_file(input).to_string())
+        }
+    }
+}
+
+/// Measures one part of a day in a child process of the given executable, which is expected to
+/// print the report of measure() after anything the solver printed itself. Running each part in
+/// its own process keeps the solver's output out of the results, gives each part a fresh heap,
+/// and allows parts which take longer than the timeout to be killed. The input is relative to dir,
+/// which the child runs in.
+pub fn measure_in_process(exe: &Path, dir: &Path, day: usize, part: usize, input: &str, timeout: Option<Duration>) -> Measurement {
+    let failed = |millis: f64, message: String| Measurement {
+        day,
+        part,
+        input: input.to_string(),
+        millis,
+        peak_bytes: 0,
+        allocations: 0,
+        result: Err(message)
+    };
+    let mut child = match Command::new(exe)
+        .args(["bench-one", &day.to_string(), &part.to_string(), input, "-q"])
+        .current_dir(dir)
+        .stdout(Stdio::piped())
+        .spawn() {
+        Ok(child) => child,
+        Err(e) => return failed(0.0, format!("Error starting {}: {}", exe.display(), e))
+    };
+    // read stdout on another thread so that a chatty solver can't fill the pipe and block
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+    let start = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) => {
+                if let Some(limit) = timeout {
+                    if start.elapsed() > limit {
+                        child.kill().ok();
+                        child.wait().ok();
+                        return failed(start.elapsed().as_secs_f64() * 1000.0, format!("Timed out after {} seconds", limit.as_secs_f64()));
+                    }
+                }
+                thread::sleep(Duration::from_millis(10));
+            },
+            Err(e) => return failed(0.0, format!("Error waiting for {}: {}", exe.display(), e))
+        }
+    }
+    let output = match reader.join() {
+        Ok(Ok(output)) => output,
+        _ => return failed(0.0, "Error reading benchmark output".to_string())
+    };
+    match Measurement::from_report(day, part, input, &output) {
+        Some(measurement) => measurement,
+        None => failed(start.elapsed().as_secs_f64() * 1000.0, "Solver exited without reporting a measurement".to_string())
+    }
+}
+
+impl Measurement {
+    /// The report printed by a child process for measure_in_process() to read
+    // eg:
+    // --- bench report ---
+    // 12.5 4096 17
+    // ok
+    // 1234
+    pub fn report(&self) -> String {
+        let (status, text) = match &self.result {
+            Ok(answer) => ("ok", answer),
+            Err(error) => ("error", error)
+        };
+        format!("{}\n{} {} {}\n{}\n{}", REPORT_MARKER, self.millis, self.peak_bytes, self.allocations, status, text)
+    }
+
+    fn from_report(day: usize, part: usize, input: &str, output: &str) -> Option<Self> {
+        let report = &output[output.rfind(REPORT_MARKER)? + REPORT_MARKER.len()..];
+        let mut lines = report.trim_start_matches(['\r', '\n']).splitn(3, '\n');
+        let numbers: Vec<&str> = lines.next()?.split_whitespace().collect();
+        if numbers.len() != 3 {
+            return None;
+        }
+        let status = lines.next()?.trim_end();
+        let text = lines.next().unwrap_or("").trim_end().to_string();
+        Some(Measurement {
+            day,
+            part,
+            input: input.to_string(),
+            millis: numbers[0].parse().ok()?,
+            peak_bytes: numbers[1].parse().ok()?,
+            allocations: numbers[2].parse().ok()?,
+            result: match status {
+                "ok" => Ok(text),
+                "error" => Err(text),
+                _ => return None
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Format should be text, json or csv: {}", s))
+        }
+    }
+}
+
+/// Renders a set of measurements in the given format, where json and csv are intended to be
+/// saved and compared between commits, so their fields should not be renamed or reordered
+pub fn render(measurements: &[Measurement], format: Format) -> String {
+    match format {
+        Format::Text => render_text(measurements),
+        Format::Json => render_json(measurements),
+        Format::Csv => render_csv(measurements)
+    }
+}
+
+fn render_text(measurements: &[Measurement]) -> String {
+    let mut lines = vec![format!("{:>3} {:>4} {:>12} {:>14} {:>12}  {}", "Day", "Part", "Time (ms)", "Peak (bytes)", "Allocations", "Answer")];
+    for m in measurements {
+        let answer = match &m.result {
+            Ok(answer) => answer.to_string(),
+            Err(error) => format!("Error: {}", error.lines().next().unwrap_or(""))
+        };
+        lines.push(format!("{:>3} {:>4} {:>12.3} {:>14} {:>12}  {}", m.day, m.part, m.millis, m.peak_bytes, m.allocations, answer));
+    }
+    lines.join("\n")
+}
+
+// eg:
+// [
+//   {"day": 1, "part": 1, "input": "day1/input.txt", "millis": 0.123, "peak_bytes": 4096, "allocations": 3, "answer": "482811", "error": null}
+// ]
+fn render_json(measurements: &[Measurement]) -> String {
+    let objects: Vec<Json> = measurements.iter().map(|m| {
+        let (answer, error) = match &m.result {
+            Ok(answer) => (answer.as_str().into(), Json::Null),
+            Err(error) => (Json::Null, error.as_str().into())
+        };
+        Json::object(vec![
+            ("day", m.day.into()),
+            ("part", m.part.into()),
+            ("input", m.input.as_str().into()),
+            ("millis", ((m.millis * 1000.0).round() / 1000.0).into()),
+            ("peak_bytes", m.peak_bytes.into()),
+            ("allocations", m.allocations.into()),
+            ("answer", answer),
+            ("error", error)
+        ])
+    }).collect();
+    json::lines(&objects)
+}
+
+fn render_csv(measurements: &[Measurement]) -> String {
+    let mut lines = vec!["day,part,input,millis,peak_bytes,allocations,answer,error".to_string()];
+    for m in measurements {
+        let (answer, error) = match &m.result {
+            Ok(answer) => (csv::field(answer), String::new()),
+            Err(error) => (String::new(), csv::field(error))
+        };
+        lines.push(format!("{},{},{},{:.3},{},{},{},{}", m.day, m.part, csv::field(&m.input), m.millis, m.peak_bytes, m.allocations, answer, error));
+    }
+    lines.join("\n")
+}
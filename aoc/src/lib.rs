@@ -0,0 +1,118 @@
+pub mod bench;
+pub mod manifest;
+
+use common::answer::{Answer, Solution};
+use common::error::Error;
+use common::gen::{Generated, Rng};
+use common::params::Params;
+
+/// Solves one part of a day, where params are any extra parameters that part needs
+pub fn solve(day: usize, part: usize, text: &str, params: &Params) -> Result<Answer, Error> {
+    match (day, part) {
+        (1, 1) => day1::part1(text, params.get_or("target", day1::TARGET)?, params.get_or("entries", 2)?),
+        (1, 2) => day1::part2(text, params.get_or("target", day1::TARGET)?, params.get_or("entries", 3)?),
+        (2, 1) => day2::part1(text, &params.get_or("policy", "count".to_string())?),
+        (2, 2) => day2::part2(text, &params.get_or("policy", "positions".to_string())?),
+        (3, 1) => day3::part1(text),
+        (3, 2) => day3::part2(text, &params.get::<day3::slope::Slopes>("slopes")?.0, params.get_or("rasterize", false)?),
+        (4, 1) => day4::part1(text, &day4::schema(params, 1)?),
+        (4, 2) => day4::part2(text, &day4::schema(params, 2)?),
+        (5, 1) => day5::part1(text),
+        (5, 2) => day5::part2(text),
+        (6, 1) => day6::part1(text),
+        (6, 2) => day6::part2(text),
+        (7, 1) => day7::part1(text),
+        (7, 2) => day7::part2(text),
+        (8, 1) => day8::part1(text),
+        (8, 2) => day8::part2(text),
+        (9, 1) => day9::part1(text, params.get("preamble")?),
+        (9, 2) => day9::part2(text, params.get("target")?),
+        (10, 1) => day10::part1(text),
+        (10, 2) => day10::part2(text),
+        (11, 1) => day11::part1(text),
+        (11, 2) => day11::part2(text),
+        (12, 1) => day12::part1(text),
+        (12, 2) => day12::part2(text),
+        (13, 1) => day13::part1(text),
+        (13, 2) => day13::part2(text),
+        (14, 1) => day14::part1(text),
+        (14, 2) => day14::part2(text),
+        (15, 1) => day15::part1(text),
+        (15, 2) => day15::part2(text),
+        (16, 1) => day16::part1(text),
+        (16, 2) => day16::part2(text),
+        (17, 1) => day17::part1(text, params.get("cycles")?),
+        (17, 2) => day17::part2(text, params.get("cycles")?),
+        (18, 1) => day18::part1(text),
+        (18, 2) => day18::part2(text),
+        (19, 1) => day19::part1(text),
+        (19, 2) => day19::part2(text),
+        (20, 1) => day20::part1(text),
+        (20, 2) => day20::part2(text),
+        (21, 1) => day21::part1(text),
+        (21, 2) => day21::part2(text),
+        (22, 1) => day22::part1(text),
+        (22, 2) => day22::part2(text),
+        (23, 1) => day23::part1(text, params.get("moves")?),
+        (23, 2) => day23::part2(text, params.get("moves")?),
+        (24, 1) => day24::part1(text),
+        (24, 2) => day24::part2(text, params.get("days")?),
+        (25, 1) => day25::part1(text),
+        _ => Err(Error::Solve(format!("No solution for day {} part {}", day, part)))
+    }
+}
+
+/// Solves one part of a day like solve(), along with any intermediate results which led to the answer
+pub fn explain(day: usize, part: usize, text: &str, params: &Params) -> Result<Solution, Error> {
+    match (day, part) {
+        (1, 1) => day1::part1_solution(text, params.get_or("target", day1::TARGET)?, params.get_or("entries", 2)?),
+        (1, 2) => day1::part2_solution(text, params.get_or("target", day1::TARGET)?, params.get_or("entries", 3)?),
+        (2, 1) => day2::part1_solution(text, &params.get_or("policy", "count".to_string())?),
+        (2, 2) => day2::part2_solution(text, &params.get_or("policy", "positions".to_string())?),
+        (3, 2) => day3::part2_solution(text, &params.get::<day3::slope::Slopes>("slopes")?.0, params.get_or("rasterize", false)?),
+        (4, 1) => day4::part1_solution(text, &day4::schema(params, 1)?),
+        (4, 2) => day4::part2_solution(text, &day4::schema(params, 2)?),
+        (13, 1) => day13::part1_solution(text),
+        (20, 1) => day20::part1_solution(text),
+        (20, 2) => day20::part2_solution(text),
+        (21, 1) => day21::part1_solution(text),
+        (21, 2) => day21::part2_solution(text),
+        (22, 1) => day22::part1_solution(text),
+        (22, 2) => day22::part2_solution(text),
+        _ => solve(day, part, text, params).map(Solution::from)
+    }
+}
+
+/// Generates an input for a day from a seed, where size is roughly how much of it there is (eg. the
+/// number of lines), which each day interprets in its own way, or None for an input like the puzzle's
+pub fn generate(day: usize, seed: u64, size: Option<usize>) -> Result<Generated, Error> {
+    let mut rng = Rng::new(seed);
+    Ok(match day {
+        1 => day1::gen::generate(&mut rng, size),
+        2 => day2::gen::generate(&mut rng, size),
+        3 => day3::gen::generate(&mut rng, size),
+        4 => day4::gen::generate(&mut rng, size),
+        5 => day5::gen::generate(&mut rng, size),
+        6 => day6::gen::generate(&mut rng, size),
+        7 => day7::gen::generate(&mut rng, size),
+        8 => day8::gen::generate(&mut rng, size),
+        9 => day9::gen::generate(&mut rng, size),
+        10 => day10::gen::generate(&mut rng, size),
+        11 => day11::gen::generate(&mut rng, size),
+        12 => day12::gen::generate(&mut rng, size),
+        13 => day13::gen::generate(&mut rng, size),
+        14 => day14::gen::generate(&mut rng, size),
+        15 => day15::gen::generate(&mut rng, size),
+        16 => day16::gen::generate(&mut rng, size),
+        17 => day17::gen::generate(&mut rng, size),
+        18 => day18::gen::generate(&mut rng, size),
+        19 => day19::gen::generate(&mut rng, size),
+        20 => day20::gen::generate(&mut rng, size),
+        21 => day21::gen::generate(&mut rng, size),
+        22 => day22::gen::generate(&mut rng, size),
+        23 => day23::gen::generate(&mut rng, size),
+        24 => day24::gen::generate(&mut rng, size),
+        25 => day25::gen::generate(&mut rng, size),
+        _ => return Err(Error::Solve(format!("No generator for day {}", day)))
+    })
+}
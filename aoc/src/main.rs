@@ -0,0 +1,295 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+use common::checkpoint;
+use common::error::Error;
+use common::info;
+use common::input;
+use common::json::{self, Json};
+use common::log;
+use common::output;
+use common::params::{self, Params};
+use common::render;
+use common::run;
+use aoc::{explain, generate};
+use aoc::bench::{self, CountingAllocator, Format};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+struct Puzzle {
+    day: usize,
+    part: usize,
+    input: &'static str, // relative to the day folder, with any parameters in its sidecar
+    slow: bool // takes hours, so is only included with --slow
+}
+
+const PUZZLES: [Puzzle; 49] = [
+    Puzzle { day: 1, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 1, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 2, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 2, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 3, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 3, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 4, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 4, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 5, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 5, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 6, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 6, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 7, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 7, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 8, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 8, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 9, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 9, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 10, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 10, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 11, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 11, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 12, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 12, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 13, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 13, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 14, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 14, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 15, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 15, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 16, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 16, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 17, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 17, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 18, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 18, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 19, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 19, part: 2, input: "input_part2.txt", slow: false },
+    Puzzle { day: 20, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 20, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 21, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 21, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 22, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 22, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 23, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 23, part: 2, input: "input.txt", slow: true },
+    Puzzle { day: 24, part: 1, input: "input.txt", slow: false },
+    Puzzle { day: 24, part: 2, input: "input.txt", slow: false },
+    Puzzle { day: 25, part: 1, input: "input.txt", slow: false },
+];
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    log::take_verbosity(&mut args);
+    if let Err(e) = run_command(&mut args) {
+        println!("Error: {}", e);
+        usage();
+        process::exit(1);
+    }
+}
+
+/// Runs the command given by the args, or an error if they are invalid
+fn run_command(args: &mut Vec<String>) -> Result<(), String> {
+    let format = if args.len() >= 2 && args[1] == "run" {
+        checkpoint::take_flags(args)?;
+        render::take_flags(args)?;
+        run::take_flags(args)?;
+        output::take_format(args)?
+    } else {
+        output::Format::Text // bench has its own formats
+    };
+    if (args.len() == 3 || args.len() == 4 && args[3] == "--slow") && args[1] == "run" && args[2] == "all" {
+        run_all(format, args.len() == 4);
+    } else if args.len() >= 5 && args[1] == "run" {
+        let day = number(&args[2], "Day")?;
+        let part = number(&args[3], "Part")?;
+        let (files, flags) = input::split_files(&args[4..]);
+        output::print_each(format, day, Some(part), files, |filename, text| {
+            Params::load(filename, Some(part), flags)
+                .and_then(|params| explain(day, part, text, &params))
+        });
+    } else if args.len() >= 3 && args[1] == "gen" {
+        let day = number(&args[2], "Day")?;
+        gen(day, &args[3..])?;
+    } else if args.len() >= 2 && args[1] == "bench" {
+        run::take_flags(args)?;
+        let format: Format = output::take_format(args)?;
+        bench_all(&args[2..], format)?;
+    } else if args.len() >= 5 && args[1] == "bench-one" {
+        // used by bench to measure each part in its own process
+        let day = number(&args[2], "Day")?;
+        let part = number(&args[3], "Part")?;
+        let filename = &args[4];
+        let text = input::read(filename)
+            .map_err(|e| format!("Error reading from {}: {}", filename, e))?;
+        let params = Params::load(filename, Some(part), &args[5..])
+            .map_err(|e| e.to_string())?;
+        println!("{}", bench::measure(day, part, filename, &text, &params).report());
+    } else {
+        usage();
+    }
+    Ok(())
+}
+
+/// A day or part given on the command line, where what is "Day" or "Part"
+fn number(arg: &str, what: &str) -> Result<usize, String> {
+    arg.parse().map_err(|_| format!("{} should be a number: {}", what, arg))
+}
+
+fn usage() {
+    println!("Please provide arguments: run <day> <part> <filename>... [--<param> <value>...] [--format text|json] [-q|-v|-vv]");
+    println!("                          [--checkpoint <file>] [--checkpoint-every <seconds>] [--resume <file>] [--timeout <seconds>]");
+    println!("                          [--render <file.png|ppm|svg|txt> [--frames]]");
+    println!("                      or: run all [--slow] [--timeout <seconds>] [--format text|json] [-q|-v|-vv]");
+    println!("                      or: bench [<day> [<part>]] [--slow] [--format text|json|csv] [--timeout <seconds>]");
+    println!("                      or: gen <day> [--seed <number>] [--size <number>] [--out <filename>] [-q]");
+}
+
+/// The folder with a folder for each day, wherever aoc is run from
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+/// Solves every part with its input, except the slow ones unless slow is set, where any part which
+/// is skipped still has an entry (with the reason as its error) so every run has the same shape
+fn run_all(format: output::Format, slow: bool) {
+    let mut results: Vec<Json> = Vec::new();
+    let timeout = run::timeout();
+    let root = root();
+    for puzzle in PUZZLES.iter() {
+        if run::cancelled() {
+            break;
+        }
+        if let Some(timeout) = timeout {
+            run::set_timeout(timeout); // for each part, rather than all of them
+        }
+        let file = format!("day{}/{}", puzzle.day, puzzle.input);
+        let filename = root.join(&file);
+        let skipped = if puzzle.slow && !slow {
+            Some("Skipped: slow, include it with --slow".to_string())
+        } else if !filename.exists() {
+            Some(format!("Skipped: {} not found", file))
+        } else {
+            None
+        };
+        let result = match &skipped {
+            Some(reason) => Err(Error::Solve(reason.clone())),
+            None => input::read(&filename)
+                .map_err(|e| Error::Solve(format!("Error reading from {}: {}", file, e)))
+                .and_then(|text| Params::load(&filename, Some(puzzle.part), &[])
+                    .and_then(|params| explain(puzzle.day, puzzle.part, &text, &params)))
+        };
+        match format {
+            output::Format::Text => match (skipped, result) {
+                (Some(reason), _) => println!("Day {} Part {}: {}", puzzle.day, puzzle.part, reason),
+                (None, Ok(solution)) => println!("Day {} Part {}: {}", puzzle.day, puzzle.part, solution.answer),
+                (None, Err(error)) => println!("Day {} Part {}: Error: {}", puzzle.day, puzzle.part, error.in_file(&file))
+            },
+            output::Format::Json => results.push(output::to_json(puzzle.day, Some(puzzle.part), &file, result))
+        }
+    }
+    if format == output::Format::Json {
+        println!("{}", json::lines(&results));
+    }
+}
+
+/// The value after a flag like --format, which is at index i of args
+fn flag_value(args: &[String], i: usize) -> Result<&str, String> {
+    args.get(i + 1).map(|value| value.as_str()).ok_or_else(|| format!("Missing value for {}", args[i]))
+}
+
+/// Measures each part (or those chosen by day and part), killing any which run past the --timeout,
+/// or an error if the arguments are invalid
+fn bench_all(args: &[String], format: Format) -> Result<(), String> {
+    let timeout = run::timeout();
+    let mut slow = false;
+    let mut filters: Vec<usize> = Vec::new();
+    for arg in args.iter() {
+        match arg.as_str() {
+            "--slow" => slow = true,
+            filter => filters.push(filter.parse().map_err(|_| format!("Day and part should be numbers: {}", filter))?)
+        }
+    }
+    let exe = env::current_exe().map_err(|e| format!("Error finding the aoc executable: {}", e))?;
+    let root = root();
+    let mut measurements = Vec::new();
+    for puzzle in PUZZLES.iter() {
+        if filters.first().is_some_and(|day| *day != puzzle.day) || filters.get(1).is_some_and(|part| *part != puzzle.part) {
+            continue;
+        }
+        if puzzle.slow && !slow {
+            eprintln!("Skipped: day {} part {} is slow, include it with --slow", puzzle.day, puzzle.part);
+            continue;
+        }
+        let file = format!("day{}/{}", puzzle.day, puzzle.input);
+        if !root.join(&file).exists() {
+            eprintln!("Skipped: {} not found", file);
+            continue;
+        }
+        if format == Format::Text {
+            eprintln!("Measuring day {} part {}...", puzzle.day, puzzle.part);
+        }
+        measurements.push(bench::measure_in_process(&exe, &root, puzzle.day, puzzle.part, &file, timeout));
+    }
+    println!("{}", bench::render(&measurements, format));
+    Ok(())
+}
+
+/// Generates an input for a day, or an error if the arguments are invalid
+fn gen(day: usize, args: &[String]) -> Result<(), String> {
+    let mut seed = None;
+    let mut size = None;
+    let mut out = None;
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--seed" => {
+                seed = Some(flag_value(args, i)?.parse().map_err(|_| "Seed should be a number".to_string())?);
+                i += 1;
+            },
+            "--size" => {
+                size = Some(flag_value(args, i)?.parse().map_err(|_| "Size should be a number".to_string())?);
+                i += 1;
+            },
+            "--out" => {
+                out = Some(flag_value(args, i)?.to_string());
+                i += 1;
+            },
+            other => return Err(format!("Unexpected argument: {}", other))
+        }
+        i += 1;
+    }
+    let seed: u64 = seed.unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64);
+    info!("Generating day {} with --seed {}", day, seed);
+    let generated = generate(day, seed, size).map_err(|e| e.to_string())?;
+    let sidecar = generated.sidecar();
+    match out {
+        Some(filename) => {
+            fs::write(&filename, format!("{}\n", generated.input))
+                .map_err(|e| format!("Error writing to {}: {}", filename, e))?;
+            if !sidecar.is_empty() {
+                let sidecar_file = params::sidecar(&filename);
+                fs::write(&sidecar_file, &sidecar)
+                    .map_err(|e| format!("Error writing to {}: {}", sidecar_file.display(), e))?;
+            }
+            // as lines for the answers.txt of the day, if the file is saved in its folder
+            let name = Path::new(&filename).file_name().unwrap().to_string_lossy().to_string();
+            for (part, answer) in generated.answers.iter() {
+                println!("{} {} = {}", part, name, answer);
+            }
+        },
+        None => {
+            println!("{}", generated.input);
+            if !sidecar.is_empty() {
+                info!("Parameters for the .toml sidecar:\n{}", sidecar.trim_end());
+            }
+            for (part, answer) in generated.answers.iter() {
+                info!("Part {}: {}", part, answer);
+            }
+        }
+    }
+    if generated.answers.is_empty() {
+        info!("No known answers for day {}", day);
+    }
+    Ok(())
+}
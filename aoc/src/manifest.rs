@@ -0,0 +1,67 @@
+use std::path::Path;
+use common::input;
+use common::error::{self, ParseError};
+
+const FILENAME: &str = "answers.txt";
+
+/// A known answer for one part of a day, when solved with the given input file and parameters
+pub struct Expected {
+    pub day: usize,
+    pub part: usize,
+    pub input: String, // relative to the day folder
+    pub overrides: Vec<(String, String)>, // parameters which differ from the sidecar of the input
+    pub answer: String,
+    pub slow: bool // too slow to check on every test run
+}
+
+/// Reads the expected answers of a day from the answers.txt in its folder, eg:
+/// ```text
+/// # part input [key=value...] = answer
+/// 1 test.txt = 295
+/// 2 input.txt = 294354277694107
+/// 2 test.txt moves=10 = 92658374
+/// slow 2 test.txt = 175594
+/// ```
+pub fn read(root: &Path, day: usize) -> Result<Vec<Expected>, ParseError> {
+    let filename = root.join(format!("day{}", day)).join(FILENAME);
+    let file = filename.display().to_string();
+    let text = input::read(&filename).map_err(|e| ParseError::new("", format!("Error reading answers: {}", e)).in_file(&file))?;
+    let mut answers = Vec::new();
+    for (index, line) in input::lines(&text).enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        answers.push(parse_line(day, line).map_err(|e| e.on_line(index + 1).in_file(&file))?);
+    }
+    Ok(answers)
+}
+
+fn parse_line(day: usize, line: &str) -> Result<Expected, ParseError> {
+    let (left, answer) = match line.find(" = ") {
+        Some(index) => (&line[..index], &line[index + 3..]),
+        None => return Err(ParseError::new(line, "Expected '[slow] <part> <input> [key=value...] = <answer>'".to_string()))
+    };
+    let mut words: Vec<&str> = left.split_whitespace().collect();
+    let slow = words.first() == Some(&"slow");
+    if slow {
+        words.remove(0);
+    }
+    if words.len() < 2 {
+        return Err(ParseError::at(line, left, "Expected a part and an input file".to_string()));
+    }
+    Ok(Expected {
+        day,
+        part: error::parse_part(line, words[0], "a part number")?,
+        input: words[1].to_string(),
+        overrides: words[2..].iter().map(|word| parse_override(line, word)).collect::<Result<_, _>>()?,
+        answer: answer.to_string(),
+        slow
+    })
+}
+
+fn parse_override(line: &str, word: &str) -> Result<(String, String), ParseError> {
+    match word.find('=') {
+        Some(index) if index > 0 => Ok((word[..index].to_string(), word[index + 1..].to_string())),
+        _ => Err(ParseError::at(line, word, format!("Expected a parameter like key=value but found '{}'", word)))
+    }
+}
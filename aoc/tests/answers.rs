@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+use common::input;
+use common::params::Params;
+use aoc::manifest;
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn check(day: usize, slow: bool) {
+    let root = root();
+    let answers = manifest::read(&root, day).unwrap_or_else(|e| panic!("Error: {}", e));
+    for expected in answers.iter().filter(|a| a.slow == slow) {
+        let filename = root.join(format!("day{}", day)).join(&expected.input);
+        let text = input::read(&filename)
+            .expect(&format!("Error reading from {}", filename.display()));
+        let mut params = Params::load(&filename, Some(expected.part), &[])
+            .unwrap_or_else(|e| panic!("Error: {}", e));
+        for (key, value) in expected.overrides.iter() {
+            params.set(key, value);
+        }
+        let result = aoc::solve(day, expected.part, &text, &params)
+            .unwrap_or_else(|e| panic!("Error: {}", e.in_file(&filename.display().to_string())));
+        assert_eq!(result.to_string(), expected.answer, "Day {} Part {} with {} {:?}", day, expected.part, expected.input, expected.overrides);
+    }
+}
+
+macro_rules! answers {
+    ($($name:ident: $day:expr),*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day, false);
+            }
+        )*
+    };
+}
+
+answers!(
+    day1: 1, day2: 2, day3: 3, day4: 4, day5: 5, day6: 6, day7: 7, day8: 8, day9: 9, day10: 10,
+    day11: 11, day12: 12, day13: 13, day14: 14, day15: 15, day16: 16, day17: 17, day18: 18, day19: 19, day20: 20,
+    day21: 21, day22: 22, day23: 23, day24: 24, day25: 25
+);
+
+#[test]
+#[ignore] // run with: cargo test -- --ignored
+fn slow_answers() {
+    for day in 1..=25 {
+        check(day, true);
+    }
+}
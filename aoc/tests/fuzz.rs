@@ -0,0 +1,106 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::time::Duration;
+use common::gen::Rng;
+use common::params::Params;
+use common::run;
+
+/// How many mutated inputs each day is given, on top of a few made entirely of random text
+const MUTATIONS: u64 = 100;
+
+/// How long a solver may run on a mutated input before giving up, since an input which still parses
+/// can easily ask for a game which never ends or an answer which doesn't exist
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+/// The timeout is shared by every solver which is running, so the days take turns
+static SERIAL: Mutex<()> = Mutex::new(());
+
+/// Characters which mutations insert, covering the separators the parsers split on, digits, letters,
+/// signs and a few that take more than one byte in UTF-8
+const ALPHABET: [char; 32] = [
+    ' ', '\n', ',', ':', '-', '+', '*', '(', ')', '#', '.', '|', '"', '=', '[', ']',
+    '0', '1', '7', '9', 'a', 'b', 'x', 'X', 'e', 'w', 'n', 's', 'L', 'é', '→', '\t'
+];
+
+/// Feeds mutations of generated inputs to both parts of a day, failing if any of them panic rather
+/// than returning an error (or an answer, as plenty of mutations are still valid inputs)
+fn fuzz(day: usize, size: Option<usize>) {
+    let _turn = SERIAL.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut rng = Rng::new(day as u64);
+    let mut failures = Vec::new();
+    for seed in 0..MUTATIONS {
+        let generated = aoc::generate(day, seed, size).unwrap_or_else(|e| panic!("Error: {}", e));
+        let input = if seed < 5 {
+            random_text(&mut rng)
+        } else {
+            mutate(&mut rng, &generated.input)
+        };
+        let sidecar = generated.sidecar();
+        for part in 1..=2 {
+            let mut params = Params::parse(&sidecar, Some(part)).unwrap_or_else(|e| panic!("Error in sidecar: {}", e));
+            match day { // shorter simulations, as mutations are about parsing rather than how long these take
+                23 => params.set("moves", "100"),
+                24 => params.set("days", "10"),
+                _ => ()
+            }
+            run::set_timeout(TIMEOUT);
+            if panic::catch_unwind(AssertUnwindSafe(|| aoc::solve(day, part, &input, &params))).is_err() {
+                failures.push(format!("Part {} panicked on input:\n{:?}", part, input));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "Day {}:\n{}", day, failures.join("\n"));
+}
+
+/// Between 1 and 4 random edits of the text, each of which deletes, inserts, repeats, swaps or
+/// truncates something
+fn mutate(rng: &mut Rng, text: &str) -> String {
+    let mut chars: Vec<char> = text.chars().collect();
+    for _ in 0..rng.range(1, 4) {
+        let length = chars.len();
+        let at = rng.range(0, length);
+        let end = rng.range(at, (at + 8).min(length));
+        match rng.below(7) {
+            0 => {
+                chars.drain(at..end);
+            },
+            1 => chars.insert(at, *rng.pick(&ALPHABET)),
+            2 if end < length => chars[end] = *rng.pick(&ALPHABET),
+            3 => {
+                let repeated: Vec<char> = chars[at..end].to_vec();
+                chars.splice(at..at, repeated);
+            },
+            4 if end < length => chars.swap(at, end),
+            5 => chars.truncate(at),
+            _ => {
+                let line: String = chars.iter().collect::<String>().lines().nth(rng.below(8)).unwrap_or("").to_string();
+                chars.splice(at..at, line.chars().chain("\n".chars()));
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Up to a few lines of characters from the alphabet
+fn random_text(rng: &mut Rng) -> String {
+    (0..rng.range(0, 200)).map(|_| *rng.pick(&ALPHABET)).collect()
+}
+
+macro_rules! fuzz {
+    ($($name:ident: $day:expr, $size:expr),*) => {
+        $(
+            #[test]
+            fn $name() {
+                fuzz($day, $size);
+            }
+        )*
+    };
+}
+
+fuzz!(
+    day1: 1, Some(20), day2: 2, Some(20), day3: 3, Some(20), day4: 4, Some(10), day5: 5, Some(20),
+    day6: 6, Some(10), day7: 7, Some(20), day8: 8, Some(30), day9: 9, Some(40), day10: 10, Some(20),
+    day11: 11, Some(10), day12: 12, Some(20), day13: 13, None, day14: 14, Some(20), day15: 15, None,
+    day16: 16, Some(20), day17: 17, Some(3), day18: 18, Some(20), day19: 19, Some(20), day20: 20, Some(3),
+    day21: 21, None, day22: 22, Some(10), day23: 23, None, day24: 24, Some(20), day25: 25, None
+);
@@ -0,0 +1,42 @@
+use common::params::Params;
+
+const SEEDS: u64 = 5;
+
+/// Solves inputs generated from several seeds, checking any answers known from how they were built,
+/// or otherwise that part 1 can at least be solved
+fn check(day: usize, size: Option<usize>) {
+    for seed in 1..=SEEDS {
+        let generated = aoc::generate(day, seed, size).unwrap_or_else(|e| panic!("Error: {}", e));
+        let sidecar = generated.sidecar();
+        let solve = |part: usize| {
+            let params = Params::parse(&sidecar, Some(part)).unwrap_or_else(|e| panic!("Error in sidecar: {}", e));
+            aoc::solve(day, part, &generated.input, &params)
+                .unwrap_or_else(|e| panic!("Error: {}, for day {} part {} with --seed {}", e, day, part, seed))
+        };
+        if generated.answers.is_empty() {
+            solve(1);
+        }
+        for (part, expected) in generated.answers.iter() {
+            assert_eq!(solve(*part), *expected, "Day {} Part {} with --seed {}", day, part, seed);
+        }
+    }
+}
+
+macro_rules! generated {
+    ($($name:ident: $day:expr, $size:expr),*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day, $size);
+            }
+        )*
+    };
+}
+
+generated!(
+    day1: 1, None, day2: 2, None, day3: 3, None, day4: 4, None, day5: 5, None,
+    day6: 6, None, day7: 7, None, day8: 8, None, day9: 9, None, day10: 10, None,
+    day11: 11, Some(20), day12: 12, None, day13: 13, None, day14: 14, None, day15: 15, None,
+    day16: 16, None, day17: 17, Some(4), day18: 18, None, day19: 19, None, day20: 20, Some(6),
+    day21: 21, None, day22: 22, Some(10), day23: 23, None, day24: 24, None, day25: 25, None
+);
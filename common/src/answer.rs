@@ -0,0 +1,85 @@
+use std::fmt;
+use crate::json::Json;
+use crate::number::Number;
+
+/// The answer to one part of a day, as it would be entered on the website
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String)
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text)
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        Answer::Number(number as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(number: isize) -> Self {
+        Answer::Number(number as i128)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(number: u32) -> Self {
+        Answer::Number(number as i128)
+    }
+}
+
+/// Numbers beyond an i128 are given as their digits, since most json readers couldn't hold them as
+/// a number anyway
+impl From<Number> for Answer {
+    fn from(number: Number) -> Self {
+        match number.to_i128() {
+            Some(small) => Answer::Number(small),
+            None => Answer::Text(number.to_string())
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<Answer> for Json {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Number(number) => Json::Number(number),
+            Answer::Text(text) => Json::Text(text)
+        }
+    }
+}
+
+/// An answer along with any intermediate results which led to it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub answer: Answer,
+    pub details: Json // Null if there is nothing more to say than the answer
+}
+
+impl Solution {
+    pub fn new<T: Into<Answer>>(answer: T, details: Json) -> Self {
+        Solution {
+            answer: answer.into(),
+            details
+        }
+    }
+}
+
+impl From<Answer> for Solution {
+    fn from(answer: Answer) -> Self {
+        Solution::new(answer, Json::Null)
+    }
+}
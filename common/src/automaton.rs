@@ -0,0 +1,163 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::hash::Hash;
+use crate::error::Error;
+use crate::run::Task;
+
+/// Which cells are alive in the next generation, based on how many of their neighbours are alive now,
+/// eg. Conway's Game of Life is B3/S23
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>
+}
+
+impl Rule {
+    /// A dead cell comes alive with any of the birth counts of live neighbours,
+    /// and a live cell stays alive with any of the survival counts
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        Rule {
+            birth: birth.to_vec(),
+            survival: survival.to_vec()
+        }
+    }
+
+    pub fn is_alive(&self, alive: bool, live_neighbours: usize) -> bool {
+        if alive {
+            self.survival.contains(&live_neighbours)
+        } else {
+            self.birth.contains(&live_neighbours)
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    // eg: B3/S23
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts = |counts: &Vec<usize>| counts.iter().map(|c| c.to_string()).collect::<Vec<String>>().join("");
+        write!(f, "B{}/S{}", counts(&self.birth), counts(&self.survival))
+    }
+}
+
+/// A state which the automaton returned to, after which it repeats forever
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize, // the first generation of the repeating states
+    pub length: usize // 1 if the state is stable
+}
+
+/// A life-like cellular automaton, where each cell is either alive or dead, and the next generation is
+/// decided by a Rule from how many of the cells returned by the neighbourhood function are alive.
+/// Any topology works, as cells are only known by their position P: a grid with line of sight, an
+/// N-dimensional lattice or a hex floor are all just different neighbourhood functions.
+pub struct Automaton<P, N> {
+    live: BTreeSet<P>,
+    neighbours: N,
+    rule: Rule,
+    cells: Option<BTreeSet<P>>,
+    generation: usize
+}
+
+impl<P, N, I> Automaton<P, N> where P: Ord + Hash + Clone, N: Fn(&P) -> I, I: IntoIterator<Item = P> {
+    /// An automaton in an infinite space, where every cell not in live is dead
+    pub fn new<L: IntoIterator<Item = P>>(live: L, neighbours: N, rule: Rule) -> Self {
+        Automaton {
+            live: live.into_iter().collect(),
+            neighbours,
+            rule,
+            cells: None,
+            generation: 0
+        }
+    }
+
+    /// Limits the space to only these cells, which is required for a rule where cells
+    /// are born with no live neighbours, as otherwise infinitely many would be
+    pub fn within<C: IntoIterator<Item = P>>(mut self, cells: C) -> Self {
+        let cells: BTreeSet<P> = cells.into_iter().collect();
+        self.live = self.live.intersection(&cells).cloned().collect();
+        self.cells = Some(cells);
+        self
+    }
+
+    /// Starts counting generations from an earlier run, eg. when resuming from a checkpoint
+    pub fn at_generation(mut self, generation: usize) -> Self {
+        self.generation = generation;
+        self
+    }
+
+    pub fn live(&self) -> &BTreeSet<P> {
+        &self.live
+    }
+
+    pub fn count(&self) -> usize {
+        self.live.len()
+    }
+
+    /// How many steps have been run
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Moves to the next generation, returning true if any cells changed
+    pub fn step(&mut self) -> bool {
+        let mut live_neighbours: HashMap<P, usize> = HashMap::new();
+        for cell in self.live.iter() {
+            for neighbour in (self.neighbours)(cell) {
+                *live_neighbours.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+        let next: BTreeSet<P> = match &self.cells {
+            Some(cells) => cells.iter()
+                .filter(|cell| self.rule.is_alive(self.live.contains(cell), *live_neighbours.get(cell).unwrap_or(&0)))
+                .cloned().collect(),
+            None => live_neighbours.keys().chain(self.live.iter()) // any other cell has no live neighbours
+                .filter(|cell| self.rule.is_alive(self.live.contains(cell), *live_neighbours.get(cell).unwrap_or(&0)))
+                .cloned().collect()
+        };
+        let changed = next != self.live;
+        self.live = next;
+        self.generation += 1;
+        changed
+    }
+
+    /// Runs this many steps, ticking the task for each
+    pub fn run(&mut self, steps: usize, task: &mut Task) -> Result<(), Error> {
+        for _ in 0..steps {
+            task.tick()?;
+            self.step();
+        }
+        Ok(())
+    }
+
+    /// Steps until nothing changes, calling after with the automaton after each step (eg. to save it
+    /// as a frame), and returning the generation which stayed the same, or an error if it repeats
+    /// without ever settling, or the task gives up first
+    pub fn run_until_stable<F: FnMut(&Self) -> Result<(), Error>>(&mut self, task: &mut Task, after: F) -> Result<usize, Error> {
+        let cycle = self.find_cycle(task, after)?;
+        if cycle.length == 1 {
+            Ok(cycle.start)
+        } else {
+            Err(Error::Solve(format!("The cells never settle, they repeat every {} steps from step {}", cycle.length, cycle.start)))
+        }
+    }
+
+    /// Steps until a state repeats, ticking the task and calling after for each step, which never
+    /// happens if the live cells keep spreading, so the task should have a limit on the generations
+    /// (or a timeout) when they might. Every state is kept to compare against, so this suits spaces
+    /// which are bounded, like the seats of day 11.
+    pub fn find_cycle<F: FnMut(&Self) -> Result<(), Error>>(&mut self, task: &mut Task, mut after: F) -> Result<Cycle, Error> {
+        let mut seen: HashMap<BTreeSet<P>, usize> = HashMap::new();
+        loop {
+            if let Some(start) = seen.get(&self.live) {
+                return Ok(Cycle {
+                    start: *start,
+                    length: self.generation - start
+                });
+            }
+            seen.insert(self.live.clone(), self.generation);
+            task.tick()?;
+            self.step();
+            after(self)?;
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use crate::error::{Error, ParseError};
+use crate::input;
+use crate::info;
+use crate::run::Task;
+
+/// The saved state of a long running simulation, written as `key = value` lines. Every checkpoint
+/// records which simulation and input it came from, and how far it got, eg.
+/// ```text
+/// simulation = day23 part2
+/// input = 8f3b2c1d0e4a5b69
+/// progress = 120000
+/// index = 4
+/// cups = 3 8 9 1 2 5 4 6 7 10 11 ...
+/// ```
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    file: String,
+    values: Vec<(String, String, usize)> // key, value, line
+}
+
+impl Checkpoint {
+    fn new() -> Self {
+        Checkpoint {
+            file: String::new(),
+            values: Vec::new()
+        }
+    }
+
+    /// Adds a value, which is kept alongside any others with the same key
+    pub fn set<T: Display>(&mut self, key: &str, value: T) {
+        let line = self.values.len() + 1;
+        self.values.push((key.to_string(), value.to_string(), line));
+    }
+
+    /// Adds a list of values separated by spaces, so each value must not contain any
+    pub fn set_list<T: Display, I: IntoIterator<Item = T>>(&mut self, key: &str, values: I) {
+        let values: Vec<String> = values.into_iter().map(|v| v.to_string()).collect();
+        self.set(key, values.join(" "));
+    }
+
+    /// The first value with this key
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<T, Error> {
+        let (value, line) = self.find(key)?;
+        self.parse(key, value, value, line)
+    }
+
+    /// The first list of values with this key
+    pub fn get_list<T: FromStr>(&self, key: &str) -> Result<Vec<T>, Error> {
+        let (values, line) = self.find(key)?;
+        values.split_whitespace().map(|value| self.parse(key, values, value, line)).collect()
+    }
+
+    /// Every value with this key, in the order they were added
+    pub fn get_all(&self, key: &str) -> Vec<&str> {
+        self.values.iter().filter(|(k, _v, _l)| k == key).map(|(_k, v, _l)| v.as_str()).collect()
+    }
+
+    /// How many steps (moves, rounds, turns, etc.) the simulation had run
+    pub fn progress(&self) -> Result<usize, Error> {
+        self.get("progress")
+    }
+
+    fn find(&self, key: &str) -> Result<(&str, usize), Error> {
+        match self.values.iter().find(|(k, _v, _l)| k == key) {
+            Some((_k, value, line)) => Ok((value, *line)),
+            None => Err(Error::Solve(format!("Checkpoint {} is missing '{}'", self.file, key)))
+        }
+    }
+
+    fn parse<T: FromStr>(&self, key: &str, values: &str, value: &str, line: usize) -> Result<T, Error> {
+        value.parse().map_err(|_| {
+            let text = format!("{} = {}", key, values);
+            let start = key.len() + 3 + (value.as_ptr() as usize - values.as_ptr() as usize); // value is a slice of values
+            ParseError::at(&text, &text[start..(start + value.len())], format!("Invalid value for {}: '{}'", key, value))
+                .on_line(line).in_file(&self.file).into()
+        })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let file = path.as_ref().display().to_string();
+        let text = input::read(&path).map_err(|e| format!("Error reading from {}: {}", file, e))?;
+        let mut checkpoint = Checkpoint::new();
+        checkpoint.file = file.clone();
+        for (index, line) in input::lines(&text).enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.find(" = ") {
+                Some(split) => checkpoint.values.push((line[..split].to_string(), line[(split + 3)..].to_string(), index + 1)),
+                None => return Err(ParseError::new(line, "Expected '<key> = <value>'".to_string()).on_line(index + 1).in_file(&file).into())
+            }
+        }
+        Ok(checkpoint)
+    }
+
+    /// Writes the checkpoint to a temporary file first, so that an interruption while saving
+    /// never leaves a half written checkpoint in place of the last good one
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let temporary = path.with_extension("tmp");
+        let mut text = String::from("# resume with --resume <this file>\n");
+        for (key, value, _line) in self.values.iter() {
+            text.push_str(&format!("{} = {}\n", key, value));
+        }
+        fs::write(&temporary, text)
+            .and_then(|_| fs::rename(&temporary, path))
+            .map_err(|e| Error::Solve(format!("Error saving checkpoint to {}: {}", path.display(), e)))
+    }
+}
+
+struct Settings {
+    save_to: Option<PathBuf>,
+    every: Duration,
+    resume: Option<PathBuf>
+}
+
+static SETTINGS: Mutex<Settings> = Mutex::new(Settings {
+    save_to: None,
+    every: Duration::from_secs(60),
+    resume: None
+});
+
+/// Removes any --checkpoint <file>, --checkpoint-every <seconds> or --resume <file> flags from the
+/// command line args, so that the remaining args can be read as usual. Resuming from a checkpoint
+/// keeps saving to the same file, unless --checkpoint names another.
+pub fn take_flags(args: &mut Vec<String>) -> Result<(), String> {
+    let mut settings = SETTINGS.lock().unwrap();
+    let mut index = 0;
+    while index < args.len() {
+        let flag = args[index].as_str();
+        if !["--checkpoint", "--checkpoint-every", "--resume"].contains(&flag) {
+            index += 1;
+            continue;
+        }
+        if index + 1 == args.len() {
+            return Err(format!("Missing value for {}", flag));
+        }
+        let value = args.remove(index + 1);
+        match args.remove(index).as_str() {
+            "--checkpoint" => settings.save_to = Some(PathBuf::from(value)),
+            "--checkpoint-every" => settings.every = match value.parse::<f64>() {
+                Ok(seconds) if seconds >= 0.0 => Duration::from_secs_f64(seconds),
+                _ => return Err(format!("Invalid value for --checkpoint-every: '{}'", value))
+            },
+            _ => {
+                if settings.save_to.is_none() {
+                    settings.save_to = Some(PathBuf::from(&value));
+                }
+                settings.resume = Some(PathBuf::from(value));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether --checkpoint or --resume was given, which only make sense for a single input, as every
+/// input would save to (and resume from) the same file
+pub fn enabled() -> bool {
+    let settings = SETTINGS.lock().unwrap();
+    settings.save_to.is_some() || settings.resume.is_some()
+}
+
+/// Periodically saves the state of a simulation to the file given by --checkpoint, and loads
+/// the state to continue from when --resume was given
+pub struct Checkpointer {
+    simulation: String,
+    input: String,
+    save_to: Option<PathBuf>,
+    every: Duration,
+    resume: Option<PathBuf>,
+    check_every: usize,
+    last_saved: Instant
+}
+
+impl Checkpointer {
+    /// Checkpoints a simulation (eg. "day23 part2") of the given input, as set up by take_flags
+    pub fn new(simulation: &str, text: &str) -> Self {
+        let settings = SETTINGS.lock().unwrap();
+        Checkpointer {
+            simulation: simulation.to_string(),
+            input: fingerprint(text),
+            save_to: settings.save_to.clone(),
+            every: settings.every,
+            resume: settings.resume.clone(),
+            check_every: 1,
+            last_saved: Instant::now()
+        }
+    }
+
+    /// Only asks whether a checkpoint is due every this many steps in tick(), rather than every
+    /// step, for hot loops where reading the clock each time would slow them down
+    pub fn check_every(mut self, steps: usize) -> Self {
+        self.check_every = steps.max(1);
+        self
+    }
+
+    /// The checkpoint to continue from, if --resume was given, which must be from the same
+    /// simulation and input, and must not have gone past the total steps (if there is a limit)
+    pub fn resume(&self, total: Option<usize>) -> Result<Option<Checkpoint>, Error> {
+        let path = match &self.resume {
+            Some(path) => path,
+            None => return Ok(None)
+        };
+        let checkpoint = Checkpoint::load(path)?;
+        let simulation: String = checkpoint.get("simulation")?;
+        if simulation != self.simulation {
+            return Err(Error::Solve(format!("Checkpoint {} is from {}, not {}", checkpoint.file, simulation, self.simulation)));
+        }
+        if checkpoint.get::<String>("input")? != self.input {
+            return Err(Error::Solve(format!("Checkpoint {} is from a different input", checkpoint.file)));
+        }
+        let progress = checkpoint.progress()?;
+        if let Some(total) = total {
+            if progress > total {
+                return Err(Error::Solve(format!("Checkpoint {} has already run {} steps, more than the {} asked for", checkpoint.file, progress, total)));
+            }
+        }
+        info!("Resuming {} from {} after {} steps", self.simulation, checkpoint.file, progress);
+        Ok(Some(checkpoint))
+    }
+
+    /// Whether --checkpoint (or --resume) gave a file to save to
+    pub fn enabled(&self) -> bool {
+        self.save_to.is_some()
+    }
+
+    /// Whether it is time to save again, which is always false without --checkpoint, but otherwise
+    /// reads the clock, so hot loops should only ask every so many steps
+    pub fn due(&self) -> bool {
+        self.save_to.is_some() && self.last_saved.elapsed() >= self.every
+    }
+
+    /// A new checkpoint for this simulation and input, after progress steps, to add the state to
+    pub fn checkpoint(&self, progress: usize) -> Checkpoint {
+        let mut checkpoint = Checkpoint::new();
+        checkpoint.set("simulation", &self.simulation);
+        checkpoint.set("input", &self.input);
+        checkpoint.set("progress", progress);
+        checkpoint
+    }
+
+    /// Ticks the task for a step of the simulation, after progress steps, and saves a checkpoint
+    /// with the state added by save when one is due, or when the task gives up so that it can be
+    /// resumed from there, returning the error from the task if it did
+    pub fn tick<F: FnOnce(&mut Checkpoint)>(&mut self, task: &mut Task, progress: usize, save: F) -> Result<(), Error> {
+        let stop = task.tick();
+        self.check(stop, progress, save)
+    }
+
+    /// The same as tick(), for a step which ticks the task itself, where stop is what the step returned
+    pub fn check<F: FnOnce(&mut Checkpoint)>(&mut self, stop: Result<(), Error>, progress: usize, save: F) -> Result<(), Error> {
+        if (progress.is_multiple_of(self.check_every) && self.due()) || (stop.is_err() && self.enabled()) {
+            let mut checkpoint = self.checkpoint(progress);
+            save(&mut checkpoint);
+            self.save(&checkpoint)?;
+        }
+        stop
+    }
+
+    pub fn save(&mut self, checkpoint: &Checkpoint) -> Result<(), Error> {
+        if let Some(path) = &self.save_to {
+            checkpoint.save(path)?;
+            info!("Saved checkpoint after {} steps to {}", checkpoint.progress()?, path.display());
+        }
+        self.last_saved = Instant::now();
+        Ok(())
+    }
+}
+
+/// A hash of the input (FNV-1a), which is stable between builds, unlike the std hashers
+fn fingerprint(text: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.trim().bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
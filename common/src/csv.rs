@@ -0,0 +1,9 @@
+/// A field of a csv line, which is quoted (with any quotes doubled) if it has a comma, quote or line
+/// break in it, otherwise as it is
+pub fn field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
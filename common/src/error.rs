@@ -0,0 +1,186 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// An error found while parsing puzzle input, which knows where in the input it occurred
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    file: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>, // starting at 1, counted in chars
+    width: usize,
+    text: String, // the full line containing the error
+    message: String
+}
+
+impl ParseError {
+    /// An error somewhere within the given line of text
+    pub fn new(text: &str, message: String) -> Self {
+        ParseError {
+            file: None,
+            line: None,
+            column: None,
+            width: 0,
+            text: text.to_string(),
+            message
+        }
+    }
+
+    /// An error at a specific part of the given line of text, where part is a slice of that text
+    pub fn at(text: &str, part: &str, message: String) -> Self {
+        let mut error = ParseError::new(text, message);
+        error.column = Some(column_of(text, part));
+        error.width = part.chars().count();
+        error
+    }
+
+    /// Points the error at a single char of the line, where column starts at 1
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self.width = 1;
+        self
+    }
+
+    /// Offsets the error by the line number which the parsed text started on, so that
+    /// parsers of nested sections can each add their own line number
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = Some(match self.line {
+            Some(relative) => line + relative - 1,
+            None => line
+        });
+        self
+    }
+
+    /// Moves an error found while parsing part of a line of text, so that it points at
+    /// that part within the full line, where part is a slice of text
+    pub fn within(mut self, text: &str, part: &str) -> Self {
+        let start = column_of(text, part);
+        self.column = Some(match self.column {
+            Some(column) => start + column - 1,
+            None => {
+                self.width = part.chars().count();
+                start
+            }
+        });
+        self.text = text.to_string();
+        self
+    }
+
+    /// Names the file the error was found in, unless it was already found in another file (eg. a sidecar)
+    pub fn in_file(mut self, file: &str) -> Self {
+        if self.file.is_none() {
+            self.file = Some(file.to_string());
+        }
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl fmt::Display for ParseError {
+    // eg:
+    // Expected a number but found 'x'
+    //  --> input.txt:3:5
+    //   |
+    // 3 | 1-x a: abcde
+    //   |   ^
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.message)?;
+        let mut location = match &self.file {
+            Some(file) => file.to_string(),
+            None => "input".to_string()
+        };
+        if let Some(line) = self.line {
+            location.push_str(&format!(":{}", line));
+            if let Some(column) = self.column {
+                location.push_str(&format!(":{}", column));
+            }
+        }
+        let number = match self.line {
+            Some(line) => line.to_string(),
+            None => String::new()
+        };
+        let gutter = " ".repeat(number.len());
+        writeln!(f, "{} --> {}", gutter, location)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.text)?;
+        let (start, width) = match self.column {
+            Some(column) => (column - 1, self.width),
+            None => (0, self.text.chars().count()) // underline the whole line
+        };
+        write!(f, "{} | {}{}", gutter, " ".repeat(start), "^".repeat(width.max(1)))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Anything which stops a puzzle from being solved
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    Parse(ParseError),
+    Solve(String)
+}
+
+impl Error {
+    pub fn in_file(self, file: &str) -> Self {
+        match self {
+            Error::Parse(error) => Error::Parse(error.in_file(file)),
+            other => other
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Solve(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::Solve(message.to_string())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(error) => write!(f, "{}", error),
+            Error::Solve(message) => write!(f, "{}", message)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses part of a line of text, describing the value which was expected if it fails
+pub fn parse_part<T: FromStr>(text: &str, part: &str, expected: &str) -> Result<T, ParseError> {
+    part.parse().map_err(|_| ParseError::at(text, part, format!("Expected {} but found '{}'", expected, part)))
+}
+
+/// The column (starting at 1) at which part begins, where part is a slice of text
+fn column_of(text: &str, part: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let offset = part.as_ptr() as usize;
+    let index = if offset >= start && offset <= start + text.len() {
+        offset - start
+    } else {
+        text.find(part).unwrap_or(0) // part was not sliced from this text
+    };
+    text[..index].chars().count() + 1
+}
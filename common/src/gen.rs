@@ -0,0 +1,107 @@
+use std::fmt::Display;
+use crate::answer::Answer;
+
+/// A small seeded random number generator (splitmix64), so that generated inputs are the same for a
+/// given seed on every platform and every version, which an external crate wouldn't promise
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng {
+            state: seed
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from low to high inclusive
+    pub fn range(&mut self, low: usize, high: usize) -> usize {
+        assert!(low <= high, "Empty range {}..={}", low, high);
+        low + (self.next_u64() % (high - low + 1) as u64) as usize
+    }
+
+    /// A number from 0 to n exclusive
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0, n - 1)
+    }
+
+    /// True with a probability of numerator/denominator
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range(0, i);
+            items.swap(i, j);
+        }
+    }
+
+    /// A word of random lower case letters, with a length from min to max inclusive
+    pub fn word(&mut self, min: usize, max: usize) -> String {
+        let length = self.range(min, max);
+        (0..length).map(|_| (b'a' + self.below(26) as u8) as char).collect()
+    }
+}
+
+/// A generated puzzle input, along with any parameters it needs and the answers which are known from
+/// the way it was built (not every day can know its answers without solving the puzzle)
+pub struct Generated {
+    pub input: String,
+    pub params: Vec<(Option<usize>, String, String)>, // part (None for all parts), key, value
+    pub answers: Vec<(usize, Answer)>
+}
+
+impl Generated {
+    pub fn new(input: String) -> Self {
+        Generated {
+            input,
+            params: Vec::new(),
+            answers: Vec::new()
+        }
+    }
+
+    /// Adds a parameter to the sidecar, for one part or all of them
+    pub fn param<T: Display>(mut self, part: Option<usize>, key: &str, value: T) -> Self {
+        self.params.push((part, key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn answer<T: Into<Answer>>(mut self, part: usize, answer: T) -> Self {
+        self.answers.push((part, answer.into()));
+        self
+    }
+
+    /// The text of the sidecar file for the parameters, which is empty if there are none
+    pub fn sidecar(&self) -> String {
+        let mut text = String::new();
+        for (_, key, value) in self.params.iter().filter(|(part, _, _)| part.is_none()) {
+            text.push_str(&format!("{} = {}\n", key, value));
+        }
+        for part in 1..=2 {
+            let values: Vec<_> = self.params.iter().filter(|(p, _, _)| *p == Some(part)).collect();
+            if !values.is_empty() {
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(&format!("[part{}]\n", part));
+                for (_, key, value) in values {
+                    text.push_str(&format!("{} = {}\n", key, value));
+                }
+            }
+        }
+        text
+    }
+}
@@ -0,0 +1,247 @@
+use std::ops::{Index, IndexMut};
+use crate::error::ParseError;
+use crate::input;
+
+/// Offsets of the 4 cells which share an edge with a cell, as (row, col) deltas
+pub const FOUR_WAY: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of the 8 cells which share an edge or a corner with a cell, as (row, col) deltas
+pub const EIGHT_WAY: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// A rectangular grid of cells, stored row by row, where positions are (row, col) starting from the top left
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    /// A grid of the given size with every cell set to value
+    pub fn new(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height]
+        }
+    }
+
+    /// A grid from rows which must all be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (index, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(format!("Row {} should be {} cells long but was {}", index + 1, width, row.len()));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    /// Parses a map with one char per cell, where cell returns None for chars which aren't
+    /// allowed, and expected describes the chars which are, eg. "'#' or '.'"
+    pub fn parse<F: Fn(char) -> Option<T>>(text: &str, expected: &str, cell: F) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (index, line) in input::lines(text).enumerate() {
+            let mut row = Vec::new();
+            for (col, c) in line.chars().enumerate() {
+                match cell(c) {
+                    Some(value) => row.push(value),
+                    None => return Err(ParseError::new(line, format!("Expected {} but found '{}'", expected, c)).at_column(col + 1).on_line(index + 1))
+                }
+            }
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::new(line, format!("Row should be {} chars long", first.len())).on_line(index + 1));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows).unwrap()) // row lengths already checked
+    }
+
+    /// Renders the grid as text with one char per cell and a new line after each row
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> String {
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            s.extend(row.iter().map(&cell));
+            s.push('\n');
+        }
+        s
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at a position, or None if it is outside the grid
+    pub fn get(&self, row: isize, col: isize) -> Option<&T> {
+        if row < 0 || col < 0 || row as usize >= self.height || col as usize >= self.width {
+            None
+        } else {
+            Some(&self.cells[row as usize * self.width + col as usize])
+        }
+    }
+
+    /// The cell at a position, as if the grid repeated forever in every direction
+    pub fn get_wrapped(&self, row: usize, col: usize) -> &T {
+        &self[(row % self.height, col % self.width)]
+    }
+
+    /// The cell offset from a position, or None if that is outside the grid
+    pub fn get_offset(&self, (row, col): (usize, usize), (row_delta, col_delta): (isize, isize)) -> Option<&T> {
+        self.get(row as isize + row_delta, col as isize + col_delta)
+    }
+
+    /// The position offset from another, or None if that is outside the grid
+    pub fn offset(&self, (row, col): (usize, usize), (row_delta, col_delta): (isize, isize)) -> Option<(usize, usize)> {
+        let (row, col) = (row as isize + row_delta, col as isize + col_delta);
+        if row < 0 || col < 0 || row as usize >= self.height || col as usize >= self.width {
+            None
+        } else {
+            Some((row as usize, col as usize))
+        }
+    }
+
+    /// The cells at each of the offsets from a position which are inside the grid, eg. EIGHT_WAY
+    pub fn neighbours<'a>(&'a self, position: (usize, usize), offsets: &'a [(isize, isize)]) -> impl Iterator<Item = &'a T> + 'a {
+        offsets.iter().filter_map(move |offset| self.get_offset(position, *offset))
+    }
+
+    /// The cells in a straight line from a position (not including it) until the edge of the grid,
+    /// along with their positions
+    pub fn ray(&self, (row, col): (usize, usize), (row_delta, col_delta): (isize, isize)) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        (1..).map_while(move |step| {
+            let (row, col) = (row as isize + row_delta * step, col as isize + col_delta * step);
+            self.get(row, col).map(|cell| ((row as usize, col as usize), cell))
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    /// Every cell along with its position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells.iter().enumerate().map(move |(index, cell)| ((index / self.width, index % self.width), cell))
+    }
+
+    pub fn count<F: Fn(&T) -> bool>(&self, predicate: F) -> usize {
+        self.cells.iter().filter(|cell| predicate(cell)).count()
+    }
+
+    /// A grid of the same size, with each cell calculated from the cell (and position) in this one
+    pub fn map<U, F: FnMut((usize, usize), &T) -> U>(&self, mut f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.positions().map(|(position, cell)| f(position, cell)).collect()
+        }
+    }
+
+    /// The part of the grid with its top left corner at (row, col)
+    pub fn sub_grid(&self, (row, col): (usize, usize), width: usize, height: usize) -> Self where T: Clone {
+        let mut cells = Vec::with_capacity(width * height);
+        for r in row..(row + height) {
+            cells.extend_from_slice(&self.row(r)[col..(col + width)]);
+        }
+        Grid { width, height, cells }
+    }
+
+    /// A copy of the grid with extra cells set to value added on every side
+    pub fn expand(&self, by: usize, value: T) -> Self where T: Clone {
+        let mut expanded = Grid::new(self.width + by * 2, self.height + by * 2, value);
+        for ((row, col), cell) in self.positions() {
+            expanded[(row + by, col + by)] = cell.clone();
+        }
+        expanded
+    }
+
+    /// Swaps rows and columns, so that the first row becomes the first column
+    pub fn transpose(&self) -> Self where T: Clone {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for col in 0..self.width {
+            cells.extend(self.column(col).cloned());
+        }
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells
+        }
+    }
+
+    /// Reverses the order of the rows, so that the top row becomes the bottom row
+    pub fn flip_vertical(&self) -> Self where T: Clone {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for row in (0..self.height).rev() {
+            cells.extend_from_slice(self.row(row));
+        }
+        Grid { cells, ..*self }
+    }
+
+    /// Reverses the order of the columns, so that the left column becomes the right column
+    pub fn flip_horizontal(&self) -> Self where T: Clone {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for row in self.rows() {
+            cells.extend(row.iter().rev().cloned());
+        }
+        Grid { cells, ..*self }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self where T: Clone {
+        self.transpose().flip_horizontal()
+    }
+
+    /// All 8 ways the grid could be rotated and flipped, in the order:
+    /// original, flipped, then each of those rotated clockwise by 90, 180 and 270 degrees
+    pub fn orientations(&self) -> [Self; 8] where T: Clone {
+        let original = self.clone();
+        let flipped = original.flip_vertical();
+        let original90 = original.rotate_clockwise();
+        let flipped90 = flipped.rotate_clockwise();
+        let original180 = original90.rotate_clockwise();
+        let flipped180 = flipped90.rotate_clockwise();
+        let original270 = original180.rotate_clockwise();
+        let flipped270 = flipped180.rotate_clockwise();
+        [
+            original, flipped,
+            original90, flipped90,
+            original180, flipped180,
+            original270, flipped270
+        ]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.height && col < self.width, "Position ({}, {}) is outside the {}x{} grid", row, col, self.width, self.height);
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < self.height && col < self.width, "Position ({}, {}) is outside the {}x{} grid", row, col, self.width, self.height);
+        &mut self.cells[row * self.width + col]
+    }
+}
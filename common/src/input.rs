@@ -0,0 +1,116 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::str::FromStr;
+use crate::error::{self, ParseError};
+
+// Puzzle inputs may be saved with either CRLF or LF line endings, and may or may not
+// end with a trailing new line, so all parsing should go through these functions
+// rather than splitting on a hard-coded line ending.
+
+/// The filename which means the input should be read from stdin instead
+pub const STDIN: &str = "-";
+
+/// Reads a file (or stdin, if the filename is -) and normalizes it, so that lines are separated by
+/// '\n' and there are no trailing blank lines
+pub fn read<P: AsRef<Path>>(filename: P) -> io::Result<String> {
+    if filename.as_ref() == Path::new(STDIN) {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(normalize(&text))
+    } else {
+        Ok(normalize(&fs::read_to_string(filename)?))
+    }
+}
+
+/// Splits command line args into the input files, which come first, and the `--key value` flags
+/// after them
+pub fn split_files(args: &[String]) -> (&[String], &[String]) {
+    let files = args.iter().take_while(|arg| !arg.starts_with("--")).count();
+    args.split_at(files)
+}
+
+pub fn normalize(text: &str) -> String {
+    let mut s = String::new();
+    for line in lines(text) {
+        s.push_str(line);
+        s.push('\n');
+    }
+    s.pop(); // no new line after the last line
+    s
+}
+
+/// Lines of the text without their line endings, excluding any trailing blank lines
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    let mut all: Vec<&str> = text.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)).collect();
+    while let Some(last) = all.last() {
+        if is_blank(last) {
+            all.pop();
+        } else {
+            break;
+        }
+    }
+    all.into_iter()
+}
+
+/// Groups of lines separated by one or more blank lines, excluding any leading or trailing blank lines
+pub fn blocks(text: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start: Option<usize> = None; // byte index of the first line in the current block
+    let mut end: usize = 0; // byte index after the last non-blank line in the current block
+    let mut index: usize = 0;
+    for raw_line in text.split('\n') {
+        let line = raw_line.strip_suffix('\r').unwrap_or(raw_line);
+        if is_blank(line) {
+            if let Some(s) = start {
+                blocks.push(&text[s..end]);
+                start = None;
+            }
+        } else {
+            if start.is_none() {
+                start = Some(index);
+            }
+            end = index + line.len();
+        }
+        index += raw_line.len() + 1;
+    }
+    if let Some(s) = start {
+        blocks.push(&text[s..end]);
+    }
+    blocks
+}
+
+/// Parses each line of the text, adding the line number to any error
+pub fn parse_lines<T: FromStr<Err = ParseError>>(text: &str) -> Result<Vec<T>, ParseError> {
+    parse_lines_with(text, |line| line.parse())
+}
+
+pub fn parse_lines_with<T, F>(text: &str, parse: F) -> Result<Vec<T>, ParseError>
+where F: Fn(&str) -> Result<T, ParseError> {
+    lines(text).enumerate().map(|(index, line)| parse(line).map_err(|e| e.on_line(index + 1))).collect()
+}
+
+/// Parses each line of the text as a single number
+pub fn parse_numbers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    lines(text).enumerate().map(|(index, line)| error::parse_part(line, line, "a number").map_err(|e| e.on_line(index + 1))).collect()
+}
+
+/// Parses each block of the text, adding the line number which the block started on to any error
+pub fn parse_blocks<T: FromStr<Err = ParseError>>(text: &str) -> Result<Vec<T>, ParseError> {
+    parse_blocks_with(text, |block| block.parse())
+}
+
+pub fn parse_blocks_with<T, F>(text: &str, parse: F) -> Result<Vec<T>, ParseError>
+where F: Fn(&str) -> Result<T, ParseError> {
+    blocks(text).into_iter().map(|block| parse(block).map_err(|e| e.on_line(line_number(text, block)))).collect()
+}
+
+/// The line number (starting at 1) on which part begins, where part is a slice of text
+pub fn line_number(text: &str, part: &str) -> usize {
+    let index = (part.as_ptr() as usize).saturating_sub(text.as_ptr() as usize).min(text.len());
+    text[..index].matches('\n').count() + 1
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
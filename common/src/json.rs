@@ -0,0 +1,119 @@
+use std::fmt;
+
+/// A JSON value, which keeps the fields of objects in the order they were given, so that
+/// anything written from it has a stable layout
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(i128),
+    Float(f64),
+    Text(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>)
+}
+
+impl Json {
+    pub fn object(fields: Vec<(&str, Json)>) -> Self {
+        Json::Object(fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+    }
+}
+
+impl fmt::Display for Json {
+    // eg: {"bus": 59, "timestamp": 944, "wait": 5}
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Number(n) => write!(f, "{}", n),
+            Json::Float(n) if n.is_finite() => write!(f, "{}", n),
+            Json::Float(_) => write!(f, "null"),
+            Json::Text(s) => write!(f, "{}", quote(s)),
+            Json::Array(values) => {
+                let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                write!(f, "[{}]", values.join(", "))
+            },
+            Json::Object(fields) => {
+                let fields: Vec<String> = fields.iter().map(|(k, v)| format!("{}: {}", quote(k), v)).collect();
+                write!(f, "{{{}}}", fields.join(", "))
+            }
+        }
+    }
+}
+
+impl From<bool> for Json {
+    fn from(b: bool) -> Self {
+        Json::Bool(b)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(n: usize) -> Self {
+        Json::Number(n as i128)
+    }
+}
+
+impl From<i128> for Json {
+    fn from(n: i128) -> Self {
+        Json::Number(n)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(n: f64) -> Self {
+        Json::Float(n)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Self {
+        Json::Text(s.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Self {
+        Json::Text(s)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(values: Vec<T>) -> Self {
+        Json::Array(values.into_iter().map(|v| v.into()).collect())
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(v) => v.into(),
+            None => Json::Null
+        }
+    }
+}
+
+/// Writes an array with one value per line, so that saved results can be diffed line by line
+pub fn lines(values: &[Json]) -> String {
+    if values.is_empty() {
+        return "[]".to_string();
+    }
+    let lines: Vec<String> = values.iter().map(|v| format!("  {}", v)).collect();
+    format!("[\n{}\n]", lines.join(",\n"))
+}
+
+fn quote(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c)
+        }
+    }
+    quoted.push('"');
+    quoted
+}
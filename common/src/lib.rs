@@ -0,0 +1,15 @@
+pub mod answer;
+pub mod automaton;
+pub mod checkpoint;
+pub mod csv;
+pub mod error;
+pub mod gen;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod number;
+pub mod output;
+pub mod params;
+pub mod render;
+pub mod run;
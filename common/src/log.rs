@@ -0,0 +1,76 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// How much a solver reports while it runs, all of which goes to stderr so that stdout only has the result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet, // -q: nothing but the result
+    Info, // the default: a summary of how the answer was found
+    Debug, // -v: each step of the solution
+    Trace // -vv: everything, including full dumps of grids and state
+}
+
+static LEVEL: AtomicUsize = AtomicUsize::new(Level::Info as usize);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as usize, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Quiet,
+        1 => Level::Info,
+        2 => Level::Debug,
+        _ => Level::Trace
+    }
+}
+
+/// Whether events at this level should be reported, which is cheap enough to check inside hot loops
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as usize
+}
+
+/// Removes any -q, -v or -vv flags from the command line args and sets the level to match,
+/// so that the remaining args can be read as usual
+pub fn take_verbosity(args: &mut Vec<String>) {
+    let mut level = Level::Info;
+    args.retain(|arg| {
+        match arg.as_str() {
+            "-q" | "--quiet" => level = Level::Quiet,
+            "-v" | "--verbose" => level = Level::Debug,
+            "-vv" => level = Level::Trace,
+            _ => return true
+        }
+        false
+    });
+    set_level(level);
+}
+
+/// Reports a summary of how the answer was found, unless -q was given
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Reports each step of the solution, when -v was given
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Reports everything, including full dumps of grids and state, when -vv was given
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
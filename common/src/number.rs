@@ -0,0 +1,170 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Rem};
+use std::str::FromStr;
+use num::bigint::BigUint;
+use num::integer::gcd;
+use num::ToPrimitive;
+
+/// A whole number of any size, for answers which grow with the input (like the product of a list of
+/// numbers), so that a huge input gives the right answer rather than wrapping around. It is kept as
+/// a u128 with checked arithmetic while it fits, which is every real input, and as a big integer
+/// from the first operation which doesn't fit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number(Value);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Small(u128),
+    Big(BigUint) // only ever larger than u128::MAX, so that each number has one representation
+}
+
+impl Number {
+    pub fn zero() -> Self {
+        Number(Value::Small(0))
+    }
+
+    pub fn one() -> Self {
+        Number(Value::Small(1))
+    }
+
+    fn from_big(big: BigUint) -> Self {
+        match big.to_u128() {
+            Some(small) => Number(Value::Small(small)),
+            None => Number(Value::Big(big))
+        }
+    }
+
+    fn to_big(&self) -> BigUint {
+        match &self.0 {
+            Value::Small(small) => BigUint::from(*small),
+            Value::Big(big) => big.clone()
+        }
+    }
+
+    /// The number as an i128, unless it is too large for one
+    pub fn to_i128(&self) -> Option<i128> {
+        match &self.0 {
+            Value::Small(small) => i128::try_from(*small).ok(),
+            Value::Big(_) => None
+        }
+    }
+
+    /// Whether this divides exactly by a divisor, where only 0 is a multiple of 0
+    pub fn is_multiple_of(&self, divisor: usize) -> bool {
+        match divisor {
+            0 => *self == Number::zero(),
+            _ => self % divisor == 0
+        }
+    }
+
+    /// The least common multiple of this and another number, which is 0 if either of them is
+    pub fn lcm(&self, other: usize) -> Number {
+        match other {
+            0 => Number::zero(),
+            _ => self.clone() * (other / gcd(other, self % other))
+        }
+    }
+}
+
+impl<T: Into<Number>> Add<T> for Number {
+    type Output = Number;
+
+    fn add(self, other: T) -> Number {
+        match (self.0, other.into().0) {
+            (Value::Small(a), Value::Small(b)) => match a.checked_add(b) {
+                Some(sum) => Number(Value::Small(sum)),
+                None => Number::from_big(BigUint::from(a) + b)
+            },
+            (a, b) => Number::from_big(Number(a).to_big() + Number(b).to_big())
+        }
+    }
+}
+
+impl<T: Into<Number>> Mul<T> for Number {
+    type Output = Number;
+
+    fn mul(self, other: T) -> Number {
+        match (self.0, other.into().0) {
+            (Value::Small(a), Value::Small(b)) => match a.checked_mul(b) {
+                Some(product) => Number(Value::Small(product)),
+                None => Number::from_big(BigUint::from(a) * b)
+            },
+            (a, b) => Number::from_big(Number(a).to_big() * Number(b).to_big())
+        }
+    }
+}
+
+impl<T: Into<Number>> AddAssign<T> for Number {
+    fn add_assign(&mut self, other: T) {
+        *self = std::mem::replace(self, Number::zero()) + other;
+    }
+}
+
+impl<T: Into<Number>> MulAssign<T> for Number {
+    fn mul_assign(&mut self, other: T) {
+        *self = std::mem::replace(self, Number::zero()) * other;
+    }
+}
+
+/// The remainder after dividing by a divisor, which must not be 0
+impl Rem<usize> for &Number {
+    type Output = usize;
+
+    fn rem(self, divisor: usize) -> usize {
+        match &self.0 {
+            Value::Small(small) => (*small % divisor as u128) as usize,
+            Value::Big(big) => (big % divisor).to_usize().unwrap() // less than the divisor, which is a usize
+        }
+    }
+}
+
+impl Sum for Number {
+    fn sum<I: Iterator<Item=Number>>(iter: I) -> Self {
+        iter.fold(Number::zero(), |total, number| total + number)
+    }
+}
+
+impl Product for Number {
+    fn product<I: Iterator<Item=Number>>(iter: I) -> Self {
+        iter.fold(Number::one(), |total, number| total * number)
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Number {
+                fn from(number: $t) -> Self {
+                    Number(Value::Small(number as u128))
+                }
+            }
+        )*
+    };
+}
+
+from_unsigned!(u8, u32, u64, u128, usize);
+
+impl FromStr for Number {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Not a number: {}", s));
+        }
+        match s.parse::<u128>() {
+            Ok(small) => Ok(Number(Value::Small(small))),
+            Err(_) => Ok(Number::from_big(s.parse::<BigUint>().map_err(|e| format!("Not a number: {} ({})", s, e))?))
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.0 {
+            Value::Small(small) => write!(f, "{}", small),
+            Value::Big(big) => write!(f, "{}", big)
+        }
+    }
+}
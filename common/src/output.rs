@@ -0,0 +1,115 @@
+use std::process;
+use std::str::FromStr;
+use crate::answer::Solution;
+use crate::checkpoint;
+use crate::error::Error;
+use crate::input;
+use crate::json::Json;
+use crate::run;
+
+/// How a binary prints the result of solving a part
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Format should be text or json: {}", s))
+        }
+    }
+}
+
+/// Removes a `--format <text|json>` flag from the command line args, if there is one,
+/// so that the remaining args can be read as usual, where a binary with other formats (like
+/// day2-audit) reads them instead, defaulting to the first
+pub fn take_format<F: FromStr<Err = String> + Default>(args: &mut Vec<String>) -> Result<F, String> {
+    match args.iter().position(|arg| arg == "--format") {
+        Some(index) => {
+            if index + 1 >= args.len() {
+                return Err("Missing value for --format".to_string());
+            }
+            let format = args[index + 1].parse()?;
+            args.drain(index..index + 2);
+            Ok(format)
+        },
+        None => Ok(F::default())
+    }
+}
+
+/// What a flag helper like take_format read, or if the flags were invalid, prints the error with the
+/// usage of the binary and exits with an error
+pub fn or_usage<T>(result: Result<T, String>, usage: &str) -> T {
+    result.unwrap_or_else(|e| {
+        println!("Error: {}", e);
+        println!("{}", usage);
+        process::exit(1);
+    })
+}
+
+/// Prints the result of solving a part (or None if the binary could solve either part) of a day
+/// with the given input file, where json is printed as a single line which always has the same fields, eg:
+/// ```text
+/// {"day": 13, "part": 1, "input": "day13/test.txt", "answer": 295, "details": {"bus": 59, "timestamp": 944, "wait": 5}, "error": null}
+/// ```
+pub fn print(format: Format, day: usize, part: Option<usize>, input: &str, result: Result<Solution, Error>) {
+    match format {
+        Format::Text => match result {
+            Ok(solution) => println!("Result: {}", solution.answer),
+            Err(error) => println!("Error: {}", error.in_file(input))
+        },
+        Format::Json => println!("{}", to_json(day, part, input, result))
+    }
+}
+
+/// Solves a part with each input file in turn (or stdin, for -) and prints each result as it comes,
+/// labelled with its file when there is more than one, where a file which can't be read is reported
+/// as an error in its place, so that the other files are still solved. Any --timeout applies to each
+/// file rather than all of them, while --checkpoint and --resume can't be used with more than one
+pub fn print_each<F>(format: Format, day: usize, part: Option<usize>, files: &[String], mut solve: F)
+    where F: FnMut(&str, &str) -> Result<Solution, Error> {
+    let timeout = run::timeout();
+    let shared_checkpoint = files.len() > 1 && checkpoint::enabled();
+    for file in files.iter() {
+        if run::cancelled() {
+            break;
+        }
+        if let Some(timeout) = timeout {
+            run::set_timeout(timeout);
+        }
+        let name = if file == input::STDIN { "stdin" } else { file.as_str() };
+        let result = if shared_checkpoint {
+            Err(Error::Solve("--checkpoint and --resume can only be used with a single input file".to_string()))
+        } else {
+            input::read(file)
+                .map_err(|e| Error::Solve(format!("Error reading from {}: {}", name, e)))
+                .and_then(|text| solve(file, &text))
+        };
+        if format == Format::Text && files.len() > 1 {
+            print!("{}: ", name);
+        }
+        print(format, day, part, name, result);
+    }
+}
+
+pub fn to_json(day: usize, part: Option<usize>, input: &str, result: Result<Solution, Error>) -> Json {
+    let (answer, details, error) = match result {
+        Ok(solution) => (solution.answer.into(), solution.details, Json::Null),
+        Err(error) => (Json::Null, Json::Null, error.in_file(input).to_string().into())
+    };
+    Json::object(vec![
+        ("day", day.into()),
+        ("part", part.into()),
+        ("input", input.into()),
+        ("answer", answer),
+        ("details", details),
+        ("error", error)
+    ])
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use crate::error::{self, Error, ParseError};
+use crate::input;
+
+/// Extra parameters needed to solve a part with a particular input (eg. the preamble size of day 9),
+/// which are read from an optional sidecar file next to the input, with the same name but a .toml
+/// extension, and can be overridden by command line flags. eg. day9/input.toml:
+/// ```text
+/// # parameters for all parts
+/// preamble = 25
+///
+/// # parameters for part 2 only, which take precedence
+/// [part2]
+/// target = 26796446
+/// ```
+pub struct Params {
+    sidecar: String,
+    values: HashMap<String, Value>,
+    overrides: HashMap<String, String>
+}
+
+struct Value {
+    line: usize,
+    text: String, // the full line of the sidecar
+    start: usize, // byte index of the value within text
+    end: usize
+}
+
+impl Params {
+    /// Reads the parameters for a part from the sidecar of the input file, if it has one, where part
+    /// is None for binaries which solve any part, then applies overrides given as `--key value` flags
+    /// (which are the only parameters for stdin)
+    pub fn load<P: AsRef<Path>>(input: P, part: Option<usize>, flags: &[String]) -> Result<Self, Error> {
+        if input.as_ref() == Path::new(input::STDIN) {
+            let mut params = Params::none(); // stdin has nowhere to put a sidecar
+            params.override_with(flags)?;
+            return Ok(params);
+        }
+        let sidecar = sidecar(input);
+        let file = sidecar.display().to_string();
+        let mut params = if sidecar.exists() {
+            let text = input::read(&sidecar).map_err(|e| format!("Error reading from {}: {}", file, e))?;
+            Params::parse(&text, part).map_err(|e| e.in_file(&file))?
+        } else {
+            Params::none()
+        };
+        params.sidecar = file;
+        params.override_with(flags)?;
+        Ok(params)
+    }
+
+    /// No parameters, to be given by overrides
+    pub fn none() -> Self {
+        Params {
+            sidecar: String::new(),
+            values: HashMap::new(),
+            overrides: HashMap::new()
+        }
+    }
+
+    /// Parses the text of a sidecar file, keeping only the parameters which apply to part
+    pub fn parse(text: &str, part: Option<usize>) -> Result<Self, ParseError> {
+        let mut general = HashMap::new();
+        let mut specific = HashMap::new();
+        let mut section: Option<usize> = None;
+        for (index, line) in input::lines(text).enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if trimmed.starts_with('[') {
+                section = Some(parse_section(line, trimmed).map_err(|e| e.on_line(index + 1))?);
+                continue;
+            }
+            let (key, value) = parse_value(line).map_err(|e| e.on_line(index + 1))?;
+            let value = Value {
+                line: index + 1,
+                text: line.to_string(),
+                start: value.as_ptr() as usize - line.as_ptr() as usize,
+                end: value.as_ptr() as usize - line.as_ptr() as usize + value.len()
+            };
+            match section {
+                None => general.insert(key.to_string(), value),
+                Some(p) if Some(p) == part => specific.insert(key.to_string(), value),
+                Some(_) => None
+            };
+        }
+        general.extend(specific);
+        Ok(Params {
+            sidecar: String::new(),
+            values: general,
+            overrides: HashMap::new()
+        })
+    }
+
+    /// Applies overrides given as `--key value` flags
+    pub fn override_with(&mut self, flags: &[String]) -> Result<(), String> {
+        let mut i = 0;
+        while i < flags.len() {
+            let key = match flags[i].strip_prefix("--") {
+                Some(key) if !key.is_empty() => key,
+                _ => return Err(format!("Expected a parameter flag like --moves but found '{}'", flags[i]))
+            };
+            let value = match flags.get(i + 1) {
+                Some(value) => value,
+                None => return Err(format!("Missing value for --{}", key))
+            };
+            self.overrides.insert(key.to_string(), value.to_string());
+            i += 2;
+        }
+        Ok(())
+    }
+
+    /// Sets a parameter as if it was given as a flag
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.overrides.insert(key.to_string(), value.to_string());
+    }
+
+    /// Whether a parameter is given, either way
+    pub fn contains(&self, key: &str) -> bool {
+        self.overrides.contains_key(key) || self.values.contains_key(key)
+    }
+
+    /// Gets an optional parameter like get(), or the default if it isn't given either way
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> Result<T, Error> {
+        if self.contains(key) {
+            self.get(key)
+        } else {
+            Ok(default)
+        }
+    }
+
+    /// Gets a required parameter which names a file, where a path in the sidecar is relative to the
+    /// sidecar itself, while a path given as a flag is relative to the current directory as usual
+    pub fn get_path(&self, key: &str) -> Result<PathBuf, Error> {
+        let path: PathBuf = self.get(key)?;
+        if self.overrides.contains_key(key) || path.is_absolute() {
+            Ok(path)
+        } else {
+            Ok(match Path::new(&self.sidecar).parent() {
+                Some(directory) => directory.join(path),
+                None => path
+            })
+        }
+    }
+
+    /// Gets a required parameter, from the overrides if given, otherwise the sidecar
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<T, Error> {
+        if let Some(value) = self.overrides.get(key) {
+            return value.parse().map_err(|_| Error::Solve(format!("Invalid value for --{}: '{}'", key, value)));
+        }
+        match self.values.get(key) {
+            Some(value) => {
+                let part = &value.text[value.start..value.end];
+                let expected = format!("a value for '{}'", key);
+                Ok(error::parse_part(&value.text, unquote(part), &expected).map_err(|e| e.on_line(value.line).in_file(&self.sidecar))?)
+            },
+            None if self.sidecar.is_empty() => Err(Error::Solve(format!("Missing parameter '{}': pass --{} <value>", key, key))),
+            None => Err(Error::Solve(format!("Missing parameter '{}': add '{} = <value>' to {} or pass --{} <value>", key, key, self.sidecar, key)))
+        }
+    }
+}
+
+/// The sidecar file for an input file, which has the same name but a .toml extension
+pub fn sidecar<P: AsRef<Path>>(input: P) -> PathBuf {
+    input.as_ref().with_extension("toml")
+}
+
+fn parse_section<'a>(line: &'a str, trimmed: &'a str) -> Result<usize, ParseError> {
+    let name = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']'))
+        .ok_or_else(|| ParseError::at(line, trimmed, "Expected a section like [part1]".to_string()))?;
+    match name.trim().strip_prefix("part") {
+        Some(number) => error::parse_part(line, number, "a part number"),
+        None => Err(ParseError::at(line, name, format!("Expected a section like [part1] but found '{}'", name)))
+    }
+}
+
+fn parse_value(line: &str) -> Result<(&str, &str), ParseError> {
+    let (key, value) = match line.find('=') {
+        Some(index) => (line[..index].trim(), line[index + 1..].trim()),
+        None => return Err(ParseError::new(line, "Expected 'key = value'".to_string()))
+    };
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(ParseError::at(line, key, format!("Expected a parameter name but found '{}'", key)));
+    }
+    if value.is_empty() {
+        return Err(ParseError::new(line, format!("Expected a value for '{}'", key)));
+    }
+    Ok((key, value))
+}
+
+fn unquote(value: &str) -> &str {
+    value.strip_prefix('"').and_then(|s| s.strip_suffix('"')).unwrap_or(value)
+}
@@ -0,0 +1,256 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use crate::error::Error;
+use crate::grid::Grid;
+use crate::info;
+
+/// How many pixels wide each cell is drawn in PNG and PPM files (SVG files scale to fit anyway)
+const CELL_SIZE: usize = 8;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const GREY: Rgb = Rgb(160, 160, 160);
+
+    fn hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A grid of coloured cells, drawn as squares, or as pointy topped hexagons where every odd row is
+/// shifted right by half a cell (the "odd-r" offset coordinates of a hex grid)
+pub struct Picture {
+    cells: Grid<Rgb>,
+    hexagonal: bool,
+    background: Rgb
+}
+
+impl Picture {
+    pub fn new(cells: Grid<Rgb>) -> Self {
+        Picture {
+            cells,
+            hexagonal: false,
+            background: Rgb::WHITE
+        }
+    }
+
+    pub fn hexagonal(mut self) -> Self {
+        self.hexagonal = true;
+        self
+    }
+
+    /// The colour around and between the cells, which shows as an outline around hexagons
+    pub fn background(mut self, colour: Rgb) -> Self {
+        self.background = colour;
+        self
+    }
+
+    /// Lays out rows of grids (which may each be a different size) with a cell of background
+    /// between them, eg. one slice of a 3D space after another
+    pub fn mosaic(rows: &[Vec<Grid<Rgb>>], background: Rgb) -> Self {
+        let row_height = |row: &Vec<Grid<Rgb>>| row.iter().map(|grid| grid.height()).max().unwrap_or(0);
+        let row_width = |row: &Vec<Grid<Rgb>>| row.iter().map(|grid| grid.width() + 1).sum::<usize>().saturating_sub(1);
+        let width = rows.iter().map(row_width).max().unwrap_or(0);
+        let height = rows.iter().map(|row| row_height(row) + 1).sum::<usize>().saturating_sub(1);
+        let mut cells = Grid::new(width, height, background);
+        let mut top = 0;
+        for row in rows.iter() {
+            let mut left = 0;
+            for grid in row.iter() {
+                for ((r, c), colour) in grid.positions() {
+                    cells[(top + r, left + c)] = *colour;
+                }
+                left += grid.width() + 1;
+            }
+            top += row_height(row) + 1;
+        }
+        Picture::new(cells).background(background)
+    }
+
+    /// Saves as a PNG, PPM or SVG file, depending on the extension
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
+        let written = match extension.as_str() {
+            "png" => self.write_png(path),
+            "ppm" => self.write_ppm(path),
+            "svg" => std::fs::write(path, self.to_svg()).map_err(|e| e.to_string()),
+            _ => return Err(Error::Solve(format!("Can't save a picture as '{}', expected a .png, .ppm or .svg file", path.display())))
+        };
+        written.map_err(|e| Error::Solve(format!("Error saving picture to {}: {}", path.display(), e)))
+    }
+
+    /// The size of a cell in pixels, which for hexagons is the width, where the height is 2/√3 times
+    /// that, and rows overlap by a quarter of the height
+    fn cell_size(&self) -> (f64, f64) {
+        let width = CELL_SIZE as f64;
+        if self.hexagonal {
+            (width, width * 2.0 / 3f64.sqrt())
+        } else {
+            (width, width)
+        }
+    }
+
+    fn pixel_size(&self) -> (usize, usize) {
+        let (cell_width, cell_height) = self.cell_size();
+        if self.hexagonal {
+            let rows = self.cells.height() as f64;
+            (((self.cells.width() as f64 + 0.5) * cell_width).ceil() as usize, ((rows * 0.75 + 0.25) * cell_height).ceil() as usize)
+        } else {
+            (self.cells.width() * CELL_SIZE, self.cells.height() * CELL_SIZE)
+        }
+    }
+
+    /// The centre of a cell in pixels
+    fn centre(&self, (row, col): (usize, usize)) -> (f64, f64) {
+        let (cell_width, cell_height) = self.cell_size();
+        if self.hexagonal {
+            let shift = if row % 2 == 1 { 0.5 } else { 0.0 };
+            ((col as f64 + 0.5 + shift) * cell_width, (row as f64 * 0.75 + 0.5) * cell_height)
+        } else {
+            ((col as f64 + 0.5) * cell_width, (row as f64 + 0.5) * cell_height)
+        }
+    }
+
+    /// The pixels as RGB bytes, row by row
+    fn raster(&self) -> Vec<u8> {
+        let (width, height) = self.pixel_size();
+        let mut pixels: Vec<Rgb> = vec![self.background; width * height];
+        let (cell_width, cell_height) = self.cell_size();
+        for (position, colour) in self.cells.positions() {
+            let (x, y) = self.centre(position);
+            let (left, top) = ((x - cell_width / 2.0).floor().max(0.0) as usize, (y - cell_height / 2.0).floor().max(0.0) as usize);
+            let (right, bottom) = (((x + cell_width / 2.0).ceil() as usize).min(width), ((y + cell_height / 2.0).ceil() as usize).min(height));
+            for py in top..bottom {
+                for px in left..right {
+                    let (dx, dy) = ((px as f64 + 0.5 - x).abs(), (py as f64 + 0.5 - y).abs());
+                    let inside = if self.hexagonal {
+                        // inset by half a pixel to leave an outline of background between hexagons
+                        let (half_width, half_height) = (cell_width / 2.0 - 0.5, cell_height / 2.0 - 0.5);
+                        dx <= half_width && dy <= half_height - dx * half_height / (2.0 * half_width)
+                    } else {
+                        dx <= cell_width / 2.0 && dy <= cell_height / 2.0
+                    };
+                    if inside {
+                        pixels[py * width + px] = *colour;
+                    }
+                }
+            }
+        }
+        pixels.iter().flat_map(|Rgb(r, g, b)| [*r, *g, *b]).collect()
+    }
+
+    fn write_png(&self, path: &Path) -> Result<(), String> {
+        let (width, height) = self.pixel_size();
+        let file = File::create(path).map_err(|e| e.to_string())?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+        writer.write_image_data(&self.raster()).map_err(|e| e.to_string())
+    }
+
+    fn write_ppm(&self, path: &Path) -> Result<(), String> {
+        let (width, height) = self.pixel_size();
+        let mut file = BufWriter::new(File::create(path).map_err(|e| e.to_string())?);
+        write!(file, "P6\n{} {}\n255\n", width, height)
+            .and_then(|_| file.write_all(&self.raster()))
+            .map_err(|e| e.to_string())
+    }
+
+    fn to_svg(&self) -> String {
+        let (width, height) = self.pixel_size();
+        let (cell_width, cell_height) = self.cell_size();
+        let mut svg = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n", width, height, width, height);
+        svg.push_str(&format!("<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>\n", width, height, self.background.hex()));
+        for (position, colour) in self.cells.positions() {
+            if *colour == self.background {
+                continue;
+            }
+            let (x, y) = self.centre(position);
+            if self.hexagonal {
+                let (w, h) = (cell_width / 2.0 - 0.5, cell_height / 2.0 - 0.5);
+                svg.push_str(&format!("<polygon points=\"{:.2},{:.2} {:.2},{:.2} {:.2},{:.2} {:.2},{:.2} {:.2},{:.2} {:.2},{:.2}\" fill=\"{}\"/>\n",
+                    x, y - h, x + w, y - h / 2.0, x + w, y + h / 2.0, x, y + h, x - w, y + h / 2.0, x - w, y - h / 2.0, colour.hex()));
+            } else {
+                svg.push_str(&format!("<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+                    x - cell_width / 2.0, y - cell_height / 2.0, cell_width, cell_height, colour.hex()));
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+struct Settings {
+    save_to: Option<PathBuf>,
+    frames: bool
+}
+
+static SETTINGS: Mutex<Settings> = Mutex::new(Settings {
+    save_to: None,
+    frames: false
+});
+
+/// Removes any --render <file> or --frames flags from the command line args, so that the remaining
+/// args can be read as usual
+pub fn take_flags(args: &mut Vec<String>) -> Result<(), String> {
+    let mut settings = SETTINGS.lock().unwrap();
+    if let Some(index) = args.iter().position(|arg| arg == "--frames") {
+        args.remove(index);
+        settings.frames = true;
+    }
+    if let Some(index) = args.iter().position(|arg| arg == "--render") {
+        if index + 1 == args.len() {
+            return Err("Missing value for --render".to_string());
+        }
+        settings.save_to = Some(PathBuf::from(args.remove(index + 1)));
+        args.remove(index);
+    }
+    if settings.frames && settings.save_to.is_none() {
+        return Err("--frames needs a --render file to number the frames after".to_string());
+    }
+    Ok(())
+}
+
+/// Saves the final state of a puzzle to the file given by --render, if any, where picture is only
+/// called when it is needed, since drawing a large grid isn't free
+pub fn save<F: FnOnce() -> Picture>(picture: F) -> Result<(), Error> {
+    let save_to = SETTINGS.lock().unwrap().save_to.clone();
+    if let Some(path) = save_to {
+        picture().save(&path)?;
+        info!("Saved picture to {}", path.display());
+    }
+    Ok(())
+}
+
+/// Saves a puzzle which is drawn as text rather than a picture to the file given by --render, if
+/// any, which should then be a .txt file
+pub fn save_text<F: FnOnce() -> String>(text: F) -> Result<(), Error> {
+    let save_to = SETTINGS.lock().unwrap().save_to.clone();
+    if let Some(path) = save_to {
+        if !path.extension().and_then(|e| e.to_str()).is_some_and(|e| e.eq_ignore_ascii_case("txt")) {
+            return Err(Error::Solve(format!("Can't save text as '{}', expected a .txt file", path.display())));
+        }
+        std::fs::write(&path, text()).map_err(|e| Error::Solve(format!("Error saving text to {}: {}", path.display(), e)))?;
+        info!("Saved text to {}", path.display());
+    }
+    Ok(())
+}
+
+/// Saves one generation of a simulation when --frames was given, next to the --render file with
+/// the generation added to its name, eg. floor-0042.png for floor.png
+pub fn save_frame<F: FnOnce() -> Picture>(generation: usize, picture: F) -> Result<(), Error> {
+    let save_to = match &*SETTINGS.lock().unwrap() {
+        Settings { save_to: Some(path), frames: true } => path.clone(),
+        _ => return Ok(())
+    };
+    let stem = save_to.file_stem().and_then(|s| s.to_str()).unwrap_or("frame");
+    let extension = save_to.extension().and_then(|e| e.to_str()).unwrap_or("png");
+    picture().save(save_to.with_file_name(format!("{}-{:04}.{}", stem, generation, extension)))
+}
@@ -0,0 +1,162 @@
+use std::process;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use crate::error::Error;
+use crate::info;
+
+/// How far a long running task has got, as given to the progress callback
+pub struct Progress<'a> {
+    pub task: &'a str,
+    pub iterations: usize,
+    pub elapsed: Duration
+}
+
+type Callback = Box<dyn Fn(&Progress) + Send>;
+
+struct Settings {
+    deadline: Option<(Instant, Duration)>, // when to stop, and the timeout it came from
+    callback: Option<Callback>
+}
+
+static SETTINGS: Mutex<Settings> = Mutex::new(Settings {
+    deadline: None,
+    callback: None
+});
+
+static CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// How often a task reports its progress
+const REPORT_EVERY: Duration = Duration::from_secs(10);
+
+/// How many iterations a task runs between checking the clock and whether it was cancelled, by default
+const CHECK_EVERY: usize = 1024;
+
+/// Removes any --timeout <seconds> flag from the command line args, so that the remaining args can be
+/// read as usual, and handles Ctrl-C by asking running tasks to stop (or quitting, if pressed again)
+pub fn take_flags(args: &mut Vec<String>) -> Result<(), String> {
+    if let Some(index) = args.iter().position(|arg| arg == "--timeout") {
+        if index + 1 == args.len() {
+            return Err("Missing value for --timeout".to_string());
+        }
+        let value = args.remove(index + 1);
+        args.remove(index);
+        match value.parse::<f64>() {
+            Ok(seconds) if seconds >= 0.0 => set_timeout(Duration::from_secs_f64(seconds)),
+            _ => return Err(format!("Invalid value for --timeout: '{}'", value))
+        }
+    }
+    ctrlc::set_handler(|| {
+        if CANCELLED.swap(true, Ordering::Relaxed) {
+            process::exit(130);
+        }
+        eprintln!("Stopping, press Ctrl-C again to quit immediately");
+    }).map_err(|e| format!("Error handling Ctrl-C: {}", e))
+}
+
+/// Stops every task which is still running after the timeout, counting from now
+pub fn set_timeout(timeout: Duration) {
+    SETTINGS.lock().unwrap().deadline = Some((Instant::now() + timeout, timeout));
+}
+
+/// The timeout given by --timeout, if any, so that it can be restarted for each of several solvers
+pub fn timeout() -> Option<Duration> {
+    SETTINGS.lock().unwrap().deadline.map(|(_deadline, timeout)| timeout)
+}
+
+/// Asks every running task to stop, as Ctrl-C does
+pub fn cancel() {
+    CANCELLED.store(true, Ordering::Relaxed);
+}
+
+pub fn cancelled() -> bool {
+    CANCELLED.load(Ordering::Relaxed)
+}
+
+/// Replaces the default progress report (a line on stderr, unless -q was given)
+pub fn on_progress<F: Fn(&Progress) + Send + 'static>(callback: F) {
+    SETTINGS.lock().unwrap().callback = Some(Box::new(callback));
+}
+
+/// A long running loop, which counts its iterations to report progress every so often, and gives up
+/// when it runs out of time, is cancelled, or goes past a limit which it should never need to reach
+pub struct Task {
+    name: String,
+    iterations: usize,
+    limit: Option<(usize, String)>, // and why there is no answer if it is reached
+    check_every: usize,
+    started: Instant,
+    last_report: Instant
+}
+
+impl Task {
+    /// A task described by what it is doing, eg. "moving cups", so that it reads as "Gave up moving cups"
+    pub fn new(name: &str) -> Self {
+        Task {
+            name: name.to_string(),
+            iterations: 0,
+            limit: None,
+            check_every: CHECK_EVERY,
+            started: Instant::now(),
+            last_report: Instant::now()
+        }
+    }
+
+    /// Gives up after this many iterations, for loops which would otherwise never end if there is no
+    /// answer, where reason explains why there can't be one
+    pub fn limit(mut self, iterations: usize, reason: &str) -> Self {
+        self.limit = Some((iterations, reason.to_string()));
+        self
+    }
+
+    /// Checks the clock and Ctrl-C every this many iterations, rather than every 1024, for loops where
+    /// each iteration takes a while, like a generation of an automaton
+    pub fn check_every(mut self, iterations: usize) -> Self {
+        self.check_every = iterations.max(1);
+        self
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Counts an iteration, returning an error if the task should stop, which is cheap enough to call
+    /// every time around a hot loop, as the clock is only read every so many iterations
+    pub fn tick(&mut self) -> Result<(), Error> {
+        self.iterations += 1;
+        if let Some((limit, reason)) = &self.limit {
+            if self.iterations >= *limit {
+                return Err(self.give_up(reason));
+            }
+        }
+        if !self.iterations.is_multiple_of(self.check_every) {
+            return Ok(());
+        }
+        if CANCELLED.load(Ordering::Relaxed) {
+            return Err(self.give_up("cancelled by Ctrl-C"));
+        }
+        let settings = SETTINGS.lock().unwrap();
+        if let Some((deadline, timeout)) = settings.deadline {
+            if Instant::now() >= deadline {
+                return Err(self.give_up(&format!("timed out after {:?}", timeout)));
+            }
+        }
+        if self.last_report.elapsed() >= REPORT_EVERY {
+            self.last_report = Instant::now();
+            let progress = Progress {
+                task: &self.name,
+                iterations: self.iterations,
+                elapsed: self.started.elapsed()
+            };
+            match &settings.callback {
+                Some(callback) => callback(&progress),
+                None => info!("Still {}: {} iterations after {}s", progress.task, progress.iterations, progress.elapsed.as_secs())
+            }
+        }
+        Ok(())
+    }
+
+    fn give_up(&self, reason: &str) -> Error {
+        Error::Solve(format!("Gave up {} after {} iterations: {}", self.name, self.iterations, reason))
+    }
+}
@@ -0,0 +1,25 @@
+use std::env;
+use common::input;
+use common::log;
+use common::output;
+use common::params::Params;
+use common::run;
+use day1::{part2_solution, TARGET};
+
+const USAGE: &str = "Please provide arguments: Filename... [--target <value>] [--entries <value>] [--timeout <seconds>] [--format text|json] [-q|-v|-vv]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    log::take_verbosity(&mut args);
+    let format = output::or_usage(output::take_format(&mut args), USAGE);
+    output::or_usage(run::take_flags(&mut args), USAGE);
+    let (files, flags) = input::split_files(&args[1..]);
+    if !files.is_empty() {
+        output::print_each(format, 1, Some(2), files, |filename, text| {
+            Params::load(filename, Some(2), flags)
+                .and_then(|params| part2_solution(text, params.get_or("target", TARGET)?, params.get_or("entries", 3)?))
+        });
+    } else {
+        println!("{}", USAGE);
+    }
+}
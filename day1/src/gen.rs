@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+use common::gen::{Generated, Rng};
+
+const TARGET: u32 = 2020;
+
+/// An expense report of size entries (200 by default), with exactly one pair and one triple which sum
+/// to 2020, where every other entry is chosen so that it doesn't make another
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(200).max(5);
+    loop {
+        let a = rng.range(1, 1009) as u32;
+        let b = rng.range(1, 1000) as u32;
+        let c = rng.range(1, 1000) as u32;
+        let mut numbers = vec![a, TARGET - a, b, c, TARGET - b - c];
+        let mut set: HashSet<u32> = numbers.iter().cloned().collect();
+        if set.len() != numbers.len() || count_pairs(&numbers, &set) != 1 || count_triples(&numbers, &set) != 1 {
+            continue;
+        }
+        while numbers.len() < size {
+            let number = filler(rng, &numbers, &set);
+            numbers.push(number);
+            set.insert(number);
+        }
+        rng.shuffle(&mut numbers);
+        let input = numbers.iter().map(|n| n.to_string()).collect::<Vec<String>>().join("\n");
+        return Generated::new(input)
+            .answer(1, a * (TARGET - a))
+            .answer(2, b * c * (TARGET - b - c));
+    }
+}
+
+/// A number which doesn't make a pair or triple with those already chosen, which is eventually above
+/// the target if there are too many numbers to find one below it
+fn filler(rng: &mut Rng, numbers: &Vec<u32>, set: &HashSet<u32>) -> u32 {
+    for _ in 0..100 {
+        let number = rng.range(1, TARGET as usize - 1) as u32;
+        if !set.contains(&number) && !set.contains(&(TARGET - number)) && !completes_triple(number, numbers, set) {
+            return number;
+        }
+    }
+    loop {
+        let number = rng.range(TARGET as usize + 1, 9999) as u32;
+        if !set.contains(&number) {
+            return number;
+        }
+    }
+}
+
+fn completes_triple(number: u32, numbers: &Vec<u32>, set: &HashSet<u32>) -> bool {
+    numbers.iter().any(|n| *n + number < TARGET && {
+        let other = TARGET - number - *n;
+        other != *n && other != number && set.contains(&other)
+    })
+}
+
+fn count_pairs(numbers: &Vec<u32>, set: &HashSet<u32>) -> usize {
+    numbers.iter().filter(|n| **n < TARGET && **n * 2 < TARGET && set.contains(&(TARGET - **n))).count()
+}
+
+fn count_triples(numbers: &Vec<u32>, set: &HashSet<u32>) -> usize {
+    let mut count = 0;
+    for (index, a) in numbers.iter().enumerate() {
+        for b in numbers[(index + 1)..].iter() {
+            if a + b < TARGET {
+                let c = TARGET - a - b;
+                if c > *a.max(b) && set.contains(&c) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
@@ -0,0 +1,92 @@
+use common::input;
+use common::answer::Solution;
+use common::error::Error;
+use common::json::Json;
+use common::number::Number;
+use common::{debug, info};
+
+/// Finds every distinct set of entries (of the given size) in the expense report which sum to the
+/// target, where the answer is the product of the first set, which has the smallest entries
+pub fn solve(text: &str, target: u64, entries: usize) -> Result<Solution, Error> {
+    if entries == 0 {
+        return Err(Error::Solve("There should be at least 1 entry to sum".to_string()));
+    }
+    let mut numbers: Vec<u64> = input::parse_numbers::<u32>(text)?.into_iter().map(u64::from).collect();
+    numbers.sort_unstable();
+    let solutions = find_all(&numbers, target, entries);
+    let first = solutions.first().ok_or_else(|| Error::Solve(format!("No {} entries sum to {}", entries, target)))?;
+    info!("Found {} set(s) of {} entries which sum to {}", solutions.len(), entries, target);
+    for solution in solutions.iter() {
+        debug!("{:?}", solution);
+    }
+    let product: Number = first.iter().map(|number| Number::from(*number)).product();
+    let sets: Vec<Vec<usize>> = solutions.iter().map(|solution| solution.iter().map(|number| *number as usize).collect()).collect();
+    Ok(Solution::new(product, Json::object(vec![
+        ("solutions", sets.into())
+    ])))
+}
+
+/// Every distinct set of k numbers which sum to the target, each in order, from numbers which are
+/// sorted, by taking each number in turn as the smallest of the set and finding the rest after it,
+/// down to a pair which is found by closing in from both ends, so it takes O(n^(k-1)) for k >= 2
+fn find_all(numbers: &[u64], target: u64, k: usize) -> Vec<Vec<u64>> {
+    let mut solutions = Vec::new();
+    match k {
+        1 => {
+            if numbers.binary_search(&target).is_ok() {
+                solutions.push(vec![target]);
+            }
+        },
+        2 => {
+            let (mut low, mut high) = (0, numbers.len());
+            while low + 1 < high {
+                let (a, b) = (numbers[low], numbers[high - 1]);
+                if a + b < target {
+                    low += 1;
+                } else if a + b > target {
+                    high -= 1;
+                } else {
+                    solutions.push(vec![a, b]);
+                    // skip repeats of both, which would only find the same pair again
+                    while low < high && numbers[low] == a {
+                        low += 1;
+                    }
+                    while high > low && numbers[high - 1] == b {
+                        high -= 1;
+                    }
+                }
+            }
+        },
+        _ => {
+            for (index, smallest) in numbers.iter().enumerate() {
+                if smallest.saturating_mul(k as u64) > target {
+                    break; // the rest of the set would be at least as large
+                }
+                if index > 0 && numbers[index - 1] == *smallest {
+                    continue; // already found every set starting with this number
+                }
+                for mut rest in find_all(&numbers[(index + 1)..], target - smallest, k - 1) {
+                    rest.insert(0, *smallest);
+                    solutions.push(rest);
+                }
+            }
+        }
+    }
+    solutions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_all;
+
+    #[test]
+    fn finds_every_distinct_set() {
+        let numbers = vec![1, 2, 2, 3, 4, 5, 5];
+        assert_eq!(find_all(&numbers, 7, 2), vec![vec![2, 5], vec![3, 4]]);
+        assert_eq!(find_all(&numbers, 10, 2), vec![vec![5, 5]]);
+        assert_eq!(find_all(&numbers, 9, 3), vec![vec![1, 3, 5], vec![2, 2, 5], vec![2, 3, 4]]);
+        assert_eq!(find_all(&numbers, 4, 1), vec![vec![4]]);
+        assert_eq!(find_all(&numbers, 6, 1), Vec::<Vec<u64>>::new());
+        assert_eq!(find_all(&numbers, 100, 4), Vec::<Vec<u64>>::new());
+    }
+}
@@ -0,0 +1,28 @@
+pub mod gen;
+pub mod ksum;
+
+use common::answer::{Answer, Solution};
+use common::error::Error;
+
+/// The sum of the entries the expense report needs fixed, unless --target says otherwise
+pub const TARGET: u64 = 2020;
+
+/// The product of the pair of entries (or as many as entries says) which sum to the target
+pub fn part1(text: &str, target: u64, entries: usize) -> Result<Answer, Error> {
+    Ok(part1_solution(text, target, entries)?.answer)
+}
+
+/// The answer to part 1 along with every set of entries which sums to the target
+pub fn part1_solution(text: &str, target: u64, entries: usize) -> Result<Solution, Error> {
+    ksum::solve(text, target, entries)
+}
+
+/// The product of the three entries (or as many as entries says) which sum to the target
+pub fn part2(text: &str, target: u64, entries: usize) -> Result<Answer, Error> {
+    Ok(part2_solution(text, target, entries)?.answer)
+}
+
+/// The answer to part 2 along with every set of entries which sums to the target
+pub fn part2_solution(text: &str, target: u64, entries: usize) -> Result<Solution, Error> {
+    ksum::solve(text, target, entries)
+}
@@ -0,0 +1,22 @@
+use std::env;
+use common::answer::Solution;
+use common::input;
+use common::log;
+use common::output;
+use common::run;
+use day10::part1;
+
+const USAGE: &str = "Please provide arguments: Filename... [--timeout <seconds>] [--format text|json] [-q|-v|-vv]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    log::take_verbosity(&mut args);
+    let format = output::or_usage(output::take_format(&mut args), USAGE);
+    output::or_usage(run::take_flags(&mut args), USAGE);
+    let (files, flags) = input::split_files(&args[1..]);
+    if !files.is_empty() && flags.is_empty() {
+        output::print_each(format, 10, Some(1), files, |_filename, text| part1(text).map(Solution::from));
+    } else {
+        println!("{}", USAGE);
+    }
+}
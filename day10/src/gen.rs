@@ -0,0 +1,37 @@
+use common::gen::{Generated, Rng};
+
+/// A bag of size adapters (100 by default, at most 110), in runs of up to 4 which each differ by 1
+/// joltage, separated by gaps of 3, so that the number of arrangements is the product of the number
+/// for each run (which could be more than fits in 64 bits with many more adapters)
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(100).clamp(1, 110);
+    let mut adapters = Vec::new();
+    let mut joltage = 0;
+    let mut ones: usize = 0;
+    let mut threes = 1; // to the device
+    let mut arrangements: u64 = 1;
+    while adapters.len() < size {
+        // a gap of 3, then a run of 1s (the first run starts from the outlet instead)
+        if !adapters.is_empty() {
+            joltage += 3;
+            adapters.push(joltage);
+            threes += 1;
+        }
+        let run = rng.range(0, 4).min(size - adapters.len());
+        for _ in 0..run {
+            joltage += 1;
+            adapters.push(joltage);
+            ones += 1;
+        }
+        arrangements *= ARRANGEMENTS[run];
+    }
+    rng.shuffle(&mut adapters);
+    let input = adapters.iter().map(|n| n.to_string()).collect::<Vec<String>>().join("\n");
+    Generated::new(input)
+        .answer(1, ones * threes)
+        .answer(2, arrangements as usize)
+}
+
+/// How many ways there are to go through a run of this many adapters, each 1 apart, with a gap of 3
+/// on either side (so only the last one is needed, and of the others no 3 in a row can be left out)
+const ARRANGEMENTS: [u64; 5] = [1, 1, 2, 4, 7];
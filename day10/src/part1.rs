@@ -1,5 +1,6 @@
-use std::env;
-use std::fs;
+use common::input;
+use common::error::Error;
+use common::info;
 
 struct JoltageDifferences {
     diff1: usize,
@@ -7,20 +8,11 @@ struct JoltageDifferences {
     diff3: usize
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let mut numbers: Vec<usize> = text.split("\r\n").map(|line| line.parse()
-            .expect(&format!("Error parsing number: {}",line))).collect();
-        let result: JoltageDifferences = process(&mut numbers).unwrap();
-        println!("Diffs: {},{},{}", result.diff1, result.diff2, result.diff3);
-        println!("Result: {}", result.diff1 * result.diff3);
-    } else {
-        println!("Please provide 1 argument: Filename");
-    }
+pub fn solve(text: &str) -> Result<usize,Error> {
+    let mut numbers: Vec<usize> = input::parse_numbers(text)?;
+    let result: JoltageDifferences = process(&mut numbers)?;
+    info!("Diffs: {},{},{}", result.diff1, result.diff2, result.diff3);
+    Ok(result.diff1 * result.diff3)
 }
 
 fn process(list: &mut Vec<usize>) -> Result<JoltageDifferences, String> {
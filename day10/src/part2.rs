@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use common::input;
+use common::error::Error;
+use common::info;
+use common::number::Number;
+
+struct AdapterSet {
+    numbers: HashSet<usize>,
+    cached_combinations: HashMap<(usize,usize),Number>
+}
+
+impl AdapterSet {
+    fn count_combinations(&mut self, from: usize, to: usize) -> Number {
+        match self.cached_combinations.get(&(from,to)) {
+            Some(result) => result.clone(),
+            None => {
+                if !self.numbers.contains(&from) && from != 0 {
+                    Number::zero()
+                } else if from+3 == to {
+                    Number::one()
+                } else {
+                    let result = self.count_combinations(from+1, to) + self.count_combinations(from+2, to) + self.count_combinations(from+3, to);
+                    self.cached_combinations.insert((from,to),result.clone());
+                    result
+                }
+            }
+        }
+    }
+}
+
+pub fn solve(text: &str) -> Result<Number,Error> {
+    let numbers: HashSet<usize> = input::parse_numbers(text)?.into_iter().collect();
+    let target = numbers.iter().max().ok_or("There are no adapters")? + 3;
+    let mut adapters = AdapterSet {
+        numbers,
+        cached_combinations: HashMap::new()
+    };
+    info!("Device joltage: {}", target);
+    Ok(adapters.count_combinations(0, target))
+}
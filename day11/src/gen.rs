@@ -0,0 +1,10 @@
+use common::gen::{Generated, Rng};
+use common::grid::Grid;
+
+/// A seating area size positions square (90 by default), where about 1 in 6 positions is floor, with
+/// no known answers since the only way to find out where people sit is to let them
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(90).max(1);
+    let seats: Grid<bool> = Grid::new(size, size, false).map(|_, _| !rng.chance(1, 6));
+    Generated::new(seats.render(|seat| if *seat { 'L' } else { '.' }))
+}
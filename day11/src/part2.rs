@@ -0,0 +1,140 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+use common::automaton::{Automaton, Rule};
+use common::error::{Error, ParseError};
+use common::grid::{Grid, EIGHT_WAY};
+use common::render::{self, Picture, Rgb};
+use common::run::Task;
+use common::trace;
+
+#[derive(Clone)]
+enum Seat {
+    Occupied,
+    Empty,
+    None
+}
+
+impl Seat {
+    fn from_char(c: &char) -> Option<Self> {
+        match *c {
+            '#' => Some(Seat::Occupied),
+            'L' => Some(Seat::Empty),
+            '.' => Some(Seat::None),
+            _ => None
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Seat::Occupied => '#',
+            Seat::Empty => 'L',
+            Seat::None => '.'
+        }
+    }
+
+    fn colour(&self) -> Rgb {
+        match self {
+            Seat::Occupied => Rgb(200, 60, 60),
+            Seat::Empty => Rgb(80, 160, 80),
+            Seat::None => Rgb(230, 230, 230)
+        }
+    }
+
+    fn is_occupied(&self) -> bool {
+        match self {
+            Seat::Occupied => true,
+            _ => false
+        }
+    }
+}
+
+#[derive(Clone)]
+struct SeatingMap {
+    seats: Grid<Seat>
+}
+
+impl SeatingMap {
+    /// The positions which have a seat, rather than floor
+    fn seats(&self) -> Vec<(usize, usize)> {
+        self.seats.positions().filter(|(_position, seat)| !matches!(seat, Seat::None)).map(|(position, _seat)| position).collect()
+    }
+
+    fn occupied_seats(&self) -> Vec<(usize, usize)> {
+        self.seats.positions().filter(|(_position, seat)| seat.is_occupied()).map(|(position, _seat)| position).collect()
+    }
+
+    fn visible_seats(&self, position: (usize, usize)) -> Vec<(usize, usize)> {
+        EIGHT_WAY.iter()
+            .filter_map(|direction| self.seats.ray(position, *direction).find(|(_position, seat)| !matches!(seat, Seat::None)))
+            .map(|(seat, _)| seat)
+            .collect()
+    }
+
+    fn with_occupied(&self, occupied: &BTreeSet<(usize, usize)>) -> SeatingMap {
+        SeatingMap {
+            seats: self.seats.map(|position, seat| match seat {
+                Seat::None => Seat::None,
+                _ if occupied.contains(&position) => Seat::Occupied,
+                _ => Seat::Empty
+            })
+        }
+    }
+
+    fn to_picture(&self) -> Picture {
+        Picture::new(self.seats.map(|_position, seat| seat.colour()))
+    }
+}
+
+impl FromStr for SeatingMap {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(SeatingMap {
+            seats: Grid::parse(text, "'#', 'L' or '.'", |c| Seat::from_char(&c))?
+        })
+    }
+}
+
+impl fmt::Display for SeatingMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.seats.render(|seat| seat.to_char()))
+    }
+}
+
+pub fn solve(text: &str) -> Result<usize, Error> {
+    let map: SeatingMap = text.parse()?;
+    let neighbours = map.seats.map(|position, _seat| map.visible_seats(position));
+    // empty seats become occupied when no visible seats are, and occupied seats empty when 5 or more visible seats are
+    let rule = Rule::new(&[0], &[0, 1, 2, 3, 4]);
+    let mut automaton = Automaton::new(map.occupied_seats(), |position: &(usize, usize)| neighbours[*position].clone(), rule)
+        .within(map.seats());
+    trace!("Step 0:\n{}", map);
+    render::save_frame(0, || map.to_picture())?;
+    let mut task = Task::new("moving seats").check_every(1);
+    automaton.run_until_stable(&mut task, |automaton| {
+        trace!("Step {}:\n{}", automaton.generation(), map.with_occupied(automaton.live()));
+        render::save_frame(automaton.generation(), || map.with_occupied(automaton.live()).to_picture())
+    })?;
+    render::save(|| map.with_occupied(automaton.live()).to_picture())?;
+    Ok(automaton.count())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::gen::Rng;
+    use super::SeatingMap;
+
+    #[test]
+    fn display_round_trips() {
+        let mut rng = Rng::new(11);
+        for _ in 0..100 {
+            let (width, height) = (rng.range(1, 20), rng.range(1, 20));
+            let rows: Vec<String> = (0..height).map(|_| (0..width).map(|_| *rng.pick(&['#', 'L', '.'])).collect()).collect();
+            let text = rows.join("\n");
+            let shown = text.parse::<SeatingMap>().unwrap().to_string();
+            assert_eq!(shown.trim_end(), text);
+            assert_eq!(shown.parse::<SeatingMap>().unwrap().to_string(), shown);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use common::gen::{Generated, Rng};
+
+/// Navigation instructions for size moves (750 by default), with turns in multiples of 90 degrees,
+/// and no known answers, since the only way to find out where the ship ends up is to follow them
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(750);
+    let lines: Vec<String> = (0..size).map(|_| match rng.below(7) {
+        0 => format!("L{}", rng.range(1, 3) * 90),
+        1 => format!("R{}", rng.range(1, 3) * 90),
+        2 | 3 => format!("F{}", rng.range(1, 100)),
+        _ => format!("{}{}", rng.pick(&['N', 'S', 'E', 'W']), rng.range(1, 5))
+    }).collect();
+    Generated::new(lines.join("\n"))
+}
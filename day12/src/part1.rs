@@ -1,10 +1,7 @@
-use std::env;
-use std::fs;
 use std::str::FromStr;
-use std::num::ParseIntError;
-use std::char::ParseCharError;
-
-const NEW_LINE: &str = "\r\n";
+use common::input;
+use common::error::{self, ParseError};
+use common::{debug, info};
 
 #[derive(Debug, Copy, Clone)]
 enum Direction {
@@ -37,31 +34,16 @@ enum Instruction {
     Forward(usize), // distance
 }
 
-#[derive(Debug)]
-enum ParseError {
-    Int(ParseIntError),
-    Char(ParseCharError),
-    Other(String)
-}
-
-impl From<ParseIntError> for ParseError {
-    fn from(e: ParseIntError) -> Self {
-        ParseError::Int(e)
-    }
-}
-
-impl From<ParseCharError> for ParseError {
-    fn from(e: ParseCharError) -> Self {
-        ParseError::Char(e)
-    }
-}
-
 impl FromStr for Instruction {
     type Err = ParseError;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let c: char = line[0..1].parse()?;
-        let n: usize = line[1..].parse()?;
+        let mut chars = line.chars();
+        let c: char = match chars.next() {
+            Some(c) => c,
+            None => return Err(ParseError::new(line, "Expected an action followed by a value".to_string()))
+        };
+        let n: usize = error::parse_part(line, chars.as_str(), "a number")?;
         match c {
             'N' => Ok(Instruction::Move(Direction::North, n)),
             'E' => Ok(Instruction::Move(Direction::East, n)),
@@ -70,7 +52,7 @@ impl FromStr for Instruction {
             'L' => Ok(Instruction::Rotate(n as isize * -1)),
             'R' => Ok(Instruction::Rotate(n as isize)),
             'F' => Ok(Instruction::Forward(n)),
-            _ => Err(ParseError::Other(format!("Incorrect char: {}",c)))
+            _ => Err(ParseError::new(line, format!("Incorrect char: {}",c)).at_column(1))
         }
     }
 }
@@ -90,7 +72,7 @@ impl Navigation {
                 Instruction::Rotate(degrees) => self.rotate(degrees),
                 Instruction::Forward(distance) => self.move_location(&self.facing.clone(), distance)
             }
-            //println!("{:?}: {:?}", instruction, self);
+            debug!("{:?}: {:?}", instruction, self);
         }
     }
 
@@ -104,7 +86,7 @@ impl Navigation {
     }
 
     fn rotate(&mut self, degrees: &isize) {
-        match ((degrees % 360) + 360) % 360 {
+        match degrees.rem_euclid(360) {
             0 => (),
             90 => self.facing = self.facing.turn_right_once(),
             180 => self.facing = self.facing.turn_right_many(2),
@@ -114,23 +96,14 @@ impl Navigation {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let instructions: Vec<Instruction> = text.split(NEW_LINE).map(|s| s.parse()
-            .expect(&format!("Error parsing instruction {}",s))).collect();
-        let mut nav = Navigation {
-            facing: Direction::East,
-            northings: 0,
-            eastings: 0
-        };
-        nav.follow(&instructions);
-        println!("Final state: {} north, {} east, facing {:?}", nav.northings, nav.eastings, nav.facing);
-        println!("Result: {}", nav.northings.abs() + nav.eastings.abs());
-    } else {
-        println!("Please provide 1 argument: Filename");
-    }
-}
\ No newline at end of file
+pub fn solve(text: &str) -> Result<isize, ParseError> {
+    let instructions: Vec<Instruction> = input::parse_lines(text)?;
+    let mut nav = Navigation {
+        facing: Direction::East,
+        northings: 0,
+        eastings: 0
+    };
+    nav.follow(&instructions);
+    info!("Final state: {} north, {} east, facing {:?}", nav.northings, nav.eastings, nav.facing);
+    Ok(nav.northings.abs() + nav.eastings.abs())
+}
@@ -1,10 +1,7 @@
-use std::env;
-use std::fs;
 use std::str::FromStr;
-use std::num::ParseIntError;
-use std::char::ParseCharError;
-
-const NEW_LINE: &str = "\r\n";
+use common::input;
+use common::error::{self, ParseError};
+use common::debug;
 
 #[derive(Debug)]
 enum Direction {
@@ -18,31 +15,16 @@ enum Instruction {
     Waypoint(usize), // multiplier
 }
 
-#[derive(Debug)]
-enum ParseError {
-    Int(ParseIntError),
-    Char(ParseCharError),
-    Other(String)
-}
-
-impl From<ParseIntError> for ParseError {
-    fn from(e: ParseIntError) -> Self {
-        ParseError::Int(e)
-    }
-}
-
-impl From<ParseCharError> for ParseError {
-    fn from(e: ParseCharError) -> Self {
-        ParseError::Char(e)
-    }
-}
-
 impl FromStr for Instruction {
     type Err = ParseError;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
-        let c: char = line[0..1].parse()?;
-        let n: usize = line[1..].parse()?;
+        let mut chars = line.chars();
+        let c: char = match chars.next() {
+            Some(c) => c,
+            None => return Err(ParseError::new(line, "Expected an action followed by a value".to_string()))
+        };
+        let n: usize = error::parse_part(line, chars.as_str(), "a number")?;
         match c {
             'N' => Ok(Instruction::Move(Direction::North, n)),
             'E' => Ok(Instruction::Move(Direction::East, n)),
@@ -51,7 +33,7 @@ impl FromStr for Instruction {
             'L' => Ok(Instruction::Rotate(n as isize * -1)),
             'R' => Ok(Instruction::Rotate(n as isize)),
             'F' => Ok(Instruction::Waypoint(n)),
-            _ => Err(ParseError::Other(format!("Incorrect char: {}",c)))
+            _ => Err(ParseError::new(line, format!("Incorrect char: {}",c)).at_column(1))
         }
     }
 }
@@ -65,10 +47,10 @@ struct Location {
 impl Location {
     fn move_location(&mut self, direction: &Direction, distance: &isize) {
         match direction {
-            Direction::North => self.northings += *distance as isize,
-            Direction::East => self.eastings += *distance as isize,
-            Direction::South => self.northings -= *distance as isize,
-            Direction::West => self.eastings -= *distance as isize,
+            Direction::North => self.northings += *distance,
+            Direction::East => self.eastings += *distance,
+            Direction::South => self.northings -= *distance,
+            Direction::West => self.eastings -= *distance,
         }
     }
 
@@ -103,12 +85,12 @@ impl Navigation {
                     self.ship.move_location(&Direction::East, &(self.waypoint.eastings * *multiplier as isize));
                 }
             }
-            //println!("{:?}: {:?}", instruction, self);
+            debug!("{:?}: {:?}", instruction, self);
         }
     }
 
     fn rotate_waypoint(&mut self, degrees: &isize) {
-        match ((degrees % 360) + 360) % 360 {
+        match degrees.rem_euclid(360) {
             0 => (),
             90 => self.waypoint.rotate_right_once(),
             180 => self.waypoint.rotate_right_many(2),
@@ -118,27 +100,18 @@ impl Navigation {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let instructions: Vec<Instruction> = text.split(NEW_LINE).map(|s| s.parse()
-            .expect(&format!("Error parsing instruction {}",s))).collect();
-        let mut nav = Navigation {
-            ship: Location {
-                northings: 0,
-                eastings: 0
-            },
-            waypoint: Location {
-                northings: 1,
-                eastings: 10
-            }
-        };
-        nav.follow(&instructions);
-        println!("Result: {}", nav.ship.northings.abs() + nav.ship.eastings.abs());
-    } else {
-        println!("Please provide 1 argument: Filename");
-    }
-}
\ No newline at end of file
+pub fn solve(text: &str) -> Result<isize, ParseError> {
+    let instructions: Vec<Instruction> = input::parse_lines(text)?;
+    let mut nav = Navigation {
+        ship: Location {
+            northings: 0,
+            eastings: 0
+        },
+        waypoint: Location {
+            northings: 1,
+            eastings: 10
+        }
+    };
+    nav.follow(&instructions);
+    Ok(nav.ship.northings.abs() + nav.ship.eastings.abs())
+}
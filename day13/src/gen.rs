@@ -0,0 +1,55 @@
+use common::gen::{Generated, Rng};
+
+/// The product of the buses, which is how often the pattern in part 2 repeats, is kept below this so
+/// that any timestamp fits in 64 bits
+const LIMIT: u64 = 1 << 60;
+
+/// Notes for size buses (9 by default, or fewer if their product would be too large), which are
+/// distinct primes, placed in the schedule so that a random timestamp is the answer to part 2
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(9).max(1);
+    let primes: Vec<u64> = (7..500).filter(|n| is_prime(*n)).collect();
+    let mut buses: Vec<u64> = Vec::new();
+    let mut product: u64 = 1;
+    for _ in 0..1000 {
+        if buses.len() == size {
+            break;
+        }
+        let bus = *rng.pick(&primes);
+        if !buses.contains(&bus) && product.saturating_mul(bus) < LIMIT {
+            buses.push(bus);
+            product *= bus;
+        }
+    }
+    // bus b is at an offset o such that (timestamp + o) % b == 0, so by the chinese remainder
+    // theorem timestamp is the earliest time that this happens for all of them
+    let timestamp = rng.next_u64() % (product - 1) + 1;
+    let mut schedule: Vec<Option<u64>> = Vec::new();
+    for bus in buses.iter() {
+        let mut offset = ((bus - timestamp % bus) % bus) as usize;
+        while schedule.get(offset).map_or(false, |slot| slot.is_some()) {
+            offset += *bus as usize;
+        }
+        if schedule.len() <= offset {
+            schedule.resize(offset + 1, None);
+        }
+        schedule[offset] = Some(*bus);
+    }
+    // part 1 needs a single bus with the shortest wait
+    let (earliest, bus, wait) = loop {
+        let earliest = rng.range(100000, 1000000) as u64;
+        let mut waits: Vec<(u64, u64)> = buses.iter().map(|bus| ((bus - earliest % bus) % bus, *bus)).collect();
+        waits.sort();
+        if waits.len() == 1 || waits[0].0 != waits[1].0 {
+            break (earliest, waits[0].1, waits[0].0);
+        }
+    };
+    let schedule: Vec<String> = schedule.iter().map(|slot| slot.map_or("x".to_string(), |bus| bus.to_string())).collect();
+    Generated::new(format!("{}\n{}", earliest, schedule.join(",")))
+        .answer(1, (bus * wait) as usize)
+        .answer(2, timestamp as usize)
+}
+
+fn is_prime(n: u64) -> bool {
+    (2..n).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
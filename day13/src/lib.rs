@@ -0,0 +1,19 @@
+pub mod gen;
+pub mod part1;
+pub mod part2;
+
+use common::answer::{Answer, Solution};
+use common::error::Error;
+
+pub fn part1(text: &str) -> Result<Answer, Error> {
+    Ok(part1_solution(text)?.answer)
+}
+
+/// The answer to part 1 along with the bus which was taken
+pub fn part1_solution(text: &str) -> Result<Solution, Error> {
+    part1::solve(text)
+}
+
+pub fn part2(text: &str) -> Result<Answer, Error> {
+    Ok(part2::solve(text)?.into())
+}
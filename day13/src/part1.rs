@@ -0,0 +1,54 @@
+use common::input;
+use common::answer::Solution;
+use common::error::{self, Error, ParseError};
+use common::run::Task;
+use common::json::Json;
+use common::info;
+
+struct BusTime {
+    bus: usize,
+    timestamp: usize,
+    wait: usize
+}
+
+fn parse_buses(line: &str) -> Result<Vec<Option<usize>>, ParseError> {
+    line.split(",").map(|s| match s {
+        "x" => Ok(None),
+        _ => error::parse_part(line, s, "a bus id or 'x'").map(Some)
+    }).collect()
+}
+
+pub fn solve(text: &str) -> Result<Solution, Error> {
+    let lines: Vec<&str> = input::lines(text).collect();
+    if lines.len() != 2 {
+        return Err(ParseError::new(lines.last().unwrap_or(&""), "Expected 2 lines: the earliest timestamp, then the bus ids".to_string()).on_line(lines.len().max(1)).into());
+    }
+    let earliest_time: usize = error::parse_part(lines[0], lines[0], "the earliest timestamp").map_err(|e| e.on_line(1))?;
+    let buses: Vec<usize> = parse_buses(lines[1]).map_err(|e| e.on_line(2))?.into_iter().flatten().collect();
+    let result = process(&buses, &earliest_time)?;
+    info!("Bus: {}, Time: {}, Wait: {}", result.bus, result.timestamp, result.wait);
+    Ok(Solution::new(result.bus * result.wait, Json::object(vec![
+        ("bus", result.bus.into()),
+        ("timestamp", result.timestamp.into()),
+        ("wait", result.wait.into())
+    ])))
+}
+
+fn process(list: &Vec<usize>, minimum: &usize) -> Result<BusTime, Error> {
+    let mut timestamp = *minimum;
+    // the smallest bus comes at least that often, so there are no buses if it hasn't come by then
+    let mut task = Task::new("waiting for a bus").limit(list.iter().min().cloned().unwrap_or(0), "no bus ever departs");
+    loop {
+        for bus in list {
+            if timestamp.is_multiple_of(*bus) {
+                return Ok(BusTime {
+                    bus: *bus,
+                    timestamp,
+                    wait: timestamp - minimum
+                })
+            }
+        }
+        task.tick()?;
+        timestamp += 1;
+    }
+}
\ No newline at end of file
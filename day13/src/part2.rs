@@ -0,0 +1,48 @@
+use common::input;
+use common::error::{self, Error, ParseError};
+use common::number::Number;
+use common::run::Task;
+use common::debug;
+
+fn parse_buses(line: &str) -> Result<Vec<Option<usize>>, ParseError> {
+    line.split(",").map(|s| match s {
+        "x" => Ok(None),
+        _ => error::parse_part(line, s, "a bus id or 'x'").map(Some)
+    }).collect()
+}
+
+pub fn solve(text: &str) -> Result<Number, Error> {
+    let lines: Vec<&str> = input::lines(text).collect();
+    if lines.len() != 2 {
+        return Err(ParseError::new(lines.last().unwrap_or(&""), "Expected 2 lines: the earliest timestamp, then the bus ids".to_string()).on_line(lines.len().max(1)).into());
+    }
+    //let earliest_time: usize = error::parse_part(lines[0], lines[0], "the earliest timestamp").map_err(|e| e.on_line(1))?;
+    let possible_buses: Vec<Option<usize>> = parse_buses(lines[1]).map_err(|e| e.on_line(2))?;
+    process(&possible_buses)
+}
+
+fn process(list: &Vec<Option<usize>>) -> Result<Number, Error> {
+    let mut timestamp = Number::zero();
+    let mut increment = Number::one();
+    for (offset, possible_bus) in list.iter().enumerate() {
+        match possible_bus {
+            Some(bus) => {
+                debug!("Looking for bus {} at timestamp {} + offset {}", bus, timestamp, offset);
+                // the offsets repeat within bus increments, so if it hasn't been found by then it never will
+                let mut task = Task::new(&format!("searching for bus {}", bus)).limit(*bus, "its departures never line up with the buses before it");
+                loop {
+                    if (timestamp.clone() + offset).is_multiple_of(*bus) {
+                        increment = increment.lcm(*bus);
+                        debug!("Found bus {} at timestamp {} + offset {} (now increment by {})", bus, timestamp, offset, increment);
+                        break;
+                    } else {
+                        task.tick()?;
+                        timestamp += increment.clone();
+                    }
+                }
+            },
+            None => ()
+        }
+    }
+    Ok(timestamp)
+}
\ No newline at end of file
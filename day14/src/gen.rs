@@ -0,0 +1,24 @@
+use common::gen::{Generated, Rng};
+
+const BITS: usize = 36;
+
+/// An initialization program of size writes (450 by default) under masks with at most 9 floating
+/// bits, to keep part 2 to 512 addresses a write, with no known answers, since the only way to find
+/// out what ends up in memory is to run it
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(450);
+    let mut lines = Vec::new();
+    let mut writes = 0;
+    while writes < size {
+        let mut mask: Vec<char> = (0..BITS).map(|_| if rng.chance(1, 2) { '0' } else { '1' }).collect();
+        for _ in 0..rng.range(0, 9) {
+            mask[rng.below(BITS)] = 'X';
+        }
+        lines.push(format!("mask = {}", mask.iter().collect::<String>()));
+        for _ in 0..rng.range(1, 6).min(size - writes) {
+            lines.push(format!("mem[{}] = {}", rng.range(0, 65535), rng.next_u64() % (1 << BITS)));
+            writes += 1;
+        }
+    }
+    Generated::new(lines.join("\n"))
+}
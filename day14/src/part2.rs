@@ -1,11 +1,10 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 use regex::Regex;
 use lazy_static::lazy_static;
 use std::str::FromStr;
+use common::input;
+use common::error::{self, ParseError};
 
-const NEW_LINE: &str = "\r\n";
 const BITS: usize = 36;
 
 #[derive(Copy, Clone)]
@@ -103,7 +102,7 @@ enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
         lazy_static! {
@@ -112,13 +111,24 @@ impl FromStr for Instruction {
         }
         match WRITE_ADDRESS.captures(line) {
             Some(mem_match) => {
-                let address: usize = mem_match.get(1).unwrap().as_str().parse().expect("This regex should only return a number");
-                let value: usize = mem_match.get(2).unwrap().as_str().parse().expect("This regex should only return a number");
+                let address: usize = error::parse_part(line, mem_match.get(1).unwrap().as_str(), "an address")?;
+                let value: usize = error::parse_part(line, mem_match.get(2).unwrap().as_str(), "a value")?;
+                for (number, part) in [(address, mem_match.get(1).unwrap()), (value, mem_match.get(2).unwrap())] {
+                    if number >> BITS != 0 {
+                        return Err(ParseError::at(line, part.as_str(), format!("Should fit in {} bits", BITS)));
+                    }
+                }
                 Ok(Instruction::WriteAddress(address, value))
             },
             None => match SET_MASK.captures(line) {
-                Some(mask_match) => Ok(Instruction::SetMask(mask_match.get(1).unwrap().as_str().to_string())),
-                None => Err(format!("Did not match regex: {}", line))
+                Some(mask_match) => {
+                    let mask = mask_match.get(1).unwrap().as_str();
+                    if mask.len() != BITS {
+                        return Err(ParseError::at(line, mask, format!("Mask should be {} characters long", BITS)));
+                    }
+                    Ok(Instruction::SetMask(mask.to_string()))
+                },
+                None => Err(ParseError::new(line, "Expected 'mask = <bits>' or 'mem[<address>] = <value>'".to_string()))
             }
         }
     }
@@ -133,23 +143,14 @@ impl Instruction {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let instructions: Vec<Instruction> = text.split(NEW_LINE).map(|s| s.parse().unwrap()).collect();
-        let mut state = MachineState {
-            mask: [BitMask::Passthrough; BITS],
-            memory: HashMap::new()
-        };
-        for instruction in instructions {
-            instruction.run(&mut state);
-        }
-        let sum: usize = state.memory.iter().map(|(_,value)| *value).sum();
-        println!("Result: {}", sum);
-    } else {
-        println!("Please provide 1 argument: Filename");
+pub fn solve(text: &str) -> Result<usize, ParseError> {
+    let instructions: Vec<Instruction> = input::parse_lines(text)?;
+    let mut state = MachineState {
+        mask: [BitMask::Passthrough; BITS],
+        memory: HashMap::new()
+    };
+    for instruction in instructions {
+        instruction.run(&mut state);
     }
+    Ok(state.memory.values().sum())
 }
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use common::checkpoint::{Checkpoint, Checkpointer};
+use common::error::{self, Error};
+use common::run::Task;
+use common::trace;
+
+pub fn solve(input: &str, steps: usize) -> Result<usize, Error> {
+    let mut checkpointer = Checkpointer::new("day15", input).check_every(100000);
+    let mut game = match checkpointer.resume(Some(steps))? {
+        Some(checkpoint) => Game::restore(&checkpoint)?,
+        None => {
+            let line = input.trim();
+            let starting_numbers: Vec<usize> = line.split(",").map(|s| error::parse_part(line, s, "a starting number")).collect::<Result<_, _>>()?;
+            Game::new(&starting_numbers)
+        }
+    };
+    let mut task = Task::new("playing the memory game");
+    while game.step < steps {
+        checkpointer.tick(&mut task, game.step, |checkpoint| game.save(checkpoint))?;
+        game.next();
+    }
+    Ok(game.last_number)
+}
+
+struct Game {
+    previous_steps: HashMap<usize,usize>,
+    last_number: usize,
+    step: usize
+}
+
+impl Game {
+    fn new(starting_numbers: &Vec<usize>) -> Self {
+        let mut previous_steps: HashMap<usize,usize> = HashMap::new();
+        let mut last_number: usize = 0;
+        for (step, number) in starting_numbers.iter().enumerate() {
+            previous_steps.insert(*number,step);
+            last_number = *number;
+            trace!("Step {}: {}", step+1, last_number);
+        }
+        previous_steps.remove(&last_number);
+        Game {
+            previous_steps,
+            last_number,
+            step: starting_numbers.len()
+        }
+    }
+
+    fn next(&mut self) {
+        let next_number: usize = match self.previous_steps.get(&self.last_number) {
+            Some(previous_step) => self.step - previous_step - 1,
+            None => 0
+        };
+        self.previous_steps.insert(self.last_number, self.step-1);
+        self.last_number = next_number;
+        self.step += 1;
+        trace!("Step {}: {}", self.step, self.last_number);
+    }
+
+    fn save(&self, checkpoint: &mut Checkpoint) {
+        checkpoint.set("last_number", self.last_number);
+        checkpoint.set_list("numbers", self.previous_steps.keys());
+        checkpoint.set_list("steps", self.previous_steps.values()); // in the same order as the keys
+    }
+
+    fn restore(checkpoint: &Checkpoint) -> Result<Self, Error> {
+        let numbers: Vec<usize> = checkpoint.get_list("numbers")?;
+        let steps: Vec<usize> = checkpoint.get_list("steps")?;
+        if numbers.len() != steps.len() {
+            return Err(Error::Solve(format!("Expected a step for each of the {} numbers but found {}", numbers.len(), steps.len())));
+        }
+        Ok(Game {
+            previous_steps: numbers.into_iter().zip(steps).collect(),
+            last_number: checkpoint.get("last_number")?,
+            step: checkpoint.progress()?
+        })
+    }
+}
@@ -0,0 +1,12 @@
+use common::gen::{Generated, Rng};
+
+/// Size distinct starting numbers (7 by default, at most 100), with no known answers, since the only
+/// way to find out what is said on a turn is to play the game up to it
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(7).clamp(1, 100);
+    let mut numbers: Vec<usize> = (0..100).collect();
+    rng.shuffle(&mut numbers);
+    let starting: Vec<String> = numbers[..size].iter().map(|n| n.to_string()).collect();
+    Generated::new(starting.join(","))
+        .param(None, "steps", 2020)
+}
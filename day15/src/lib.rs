@@ -0,0 +1,13 @@
+pub mod game;
+pub mod gen;
+
+use common::answer::Answer;
+use common::error::Error;
+
+pub fn part1(text: &str) -> Result<Answer, Error> {
+    Ok(game::solve(text, 2020)?.into())
+}
+
+pub fn part2(text: &str) -> Result<Answer, Error> {
+    Ok(game::solve(text, 30000000)?.into())
+}
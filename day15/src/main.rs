@@ -0,0 +1,29 @@
+use std::env;
+use common::answer::{Answer, Solution};
+use common::checkpoint;
+use common::input;
+use common::log;
+use common::output;
+use common::params::Params;
+use common::run;
+use day15::game;
+
+const USAGE: &str = "Please provide arguments: Filename... [--steps <value>] [--checkpoint <file>] [--checkpoint-every <seconds>] [--resume <file>] [--timeout <seconds>] [--format text|json] [-q|-v|-vv]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    log::take_verbosity(&mut args);
+    let format = output::or_usage(output::take_format(&mut args), USAGE);
+    output::or_usage(checkpoint::take_flags(&mut args), USAGE);
+    output::or_usage(run::take_flags(&mut args), USAGE);
+    let (files, flags) = input::split_files(&args[1..]);
+    if !files.is_empty() {
+        output::print_each(format, 15, None, files, |filename, text| {
+            Params::load(filename, None, flags)
+                .and_then(|params| game::solve(text, params.get("steps")?))
+                .map(|number| Solution::from(Answer::from(number)))
+        });
+    } else {
+        println!("{}", USAGE);
+    }
+}
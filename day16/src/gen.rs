@@ -0,0 +1,57 @@
+use common::gen::{Generated, Rng};
+
+const FIELDS: [&str; 20] = ["departure location", "departure station", "departure platform", "departure track", "departure date", "departure time", "arrival location", "arrival station", "arrival platform", "arrival track", "class", "duration", "price", "route", "row", "seat", "train", "type", "wagon", "zone"];
+const LOWEST: usize = 25; // values below this are invalid for every field
+const ZONE: usize = 40;
+const HIGHEST: usize = 999; // values above LOWEST + FIELDS.len() * ZONE, up to this, are invalid too
+
+/// Notes on size nearby tickets (240 by default), a fifth of which have an invalid value, where the
+/// rule for each field accepts one more zone of values than the last, and the column for that field
+/// has a value in its highest zone, so that exactly one field can be in the column with the fewest
+/// zones, then one more in the next, and so on
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(240).max(1);
+    let count = FIELDS.len();
+    // the field accepting rank + 1 zones is names[rank], and is at columns[rank] on the tickets
+    let mut names: Vec<&str> = FIELDS.to_vec();
+    rng.shuffle(&mut names);
+    let mut columns: Vec<usize> = (0..count).collect();
+    rng.shuffle(&mut columns);
+    let mut rules: Vec<String> = (0..count).map(|rank| {
+        let highest = LOWEST + (rank + 1) * ZONE - 1;
+        let split = rng.range(LOWEST, highest - 1);
+        format!("{}: {}-{} or {}-{}", names[rank], LOWEST, split, split + 1, highest)
+    }).collect();
+    rng.shuffle(&mut rules);
+    let ticket = |rng: &mut Rng, highest_zone: bool| -> Vec<usize> {
+        let mut values = vec![0; count];
+        for rank in 0..count {
+            let zone = if highest_zone { rank } else { rng.range(0, rank) };
+            values[columns[rank]] = LOWEST + zone * ZONE + rng.below(ZONE);
+        }
+        values
+    };
+    let yours = ticket(rng, false);
+    let mut nearby = vec![ticket(rng, true)];
+    let mut error_rate = 0;
+    while nearby.len() < size {
+        let mut values = ticket(rng, false);
+        if rng.chance(1, 5) {
+            let invalid = if rng.chance(1, 2) {
+                rng.range(1, LOWEST - 1)
+            } else {
+                rng.range(LOWEST + count * ZONE, HIGHEST)
+            };
+            values[rng.below(count)] = invalid;
+            error_rate += invalid;
+        }
+        nearby.push(values);
+    }
+    rng.shuffle(&mut nearby);
+    let departure: usize = (0..count).filter(|rank| names[*rank].starts_with("departure")).map(|rank| yours[columns[rank]]).product();
+    let line = |values: &Vec<usize>| values.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(",");
+    let input = format!("{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}", rules.join("\n"), line(&yours), nearby.iter().map(line).collect::<Vec<String>>().join("\n"));
+    Generated::new(input)
+        .answer(1, error_rate)
+        .answer(2, departure)
+}
@@ -0,0 +1,100 @@
+use regex::Regex;
+use lazy_static::lazy_static;
+use std::str::FromStr;
+use common::input;
+use common::log::{self, Level};
+use common::error::{self, ParseError};
+use common::debug;
+
+struct Rule {
+    _field: String,
+    min1: usize,
+    max1: usize,
+    min2: usize,
+    max2: usize
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RULE_REGEX: Regex = Regex::new("^(.*): (\\d*)-(\\d*) or (\\d*)-(\\d*)$").unwrap();
+        }
+        match RULE_REGEX.captures(line) {
+            Some(rule_match) => Ok(Rule {
+                _field: rule_match.get(1).unwrap().as_str().to_string(),
+                min1: error::parse_part(line, rule_match.get(2).unwrap().as_str(), "a number")?,
+                max1: error::parse_part(line, rule_match.get(3).unwrap().as_str(), "a number")?,
+                min2: error::parse_part(line, rule_match.get(4).unwrap().as_str(), "a number")?,
+                max2: error::parse_part(line, rule_match.get(5).unwrap().as_str(), "a number")?,
+            }),
+            None => Err(ParseError::new(line, "Expected '<field>: <min>-<max> or <min>-<max>'".to_string()))
+        }
+    }
+}
+
+impl Rule {
+    fn verify(&self, value: usize) -> bool {
+        value >= self.min1 && value <= self.max1 || value >= self.min2 && value <= self.max2
+    }
+}
+
+#[derive(Debug)]
+struct Ticket {
+    values: Vec<usize>
+}
+
+impl FromStr for Ticket {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        Ok(Ticket {
+            values: line.split(",").map(|s| error::parse_part(line, s, "a number")).collect::<Result<_, _>>()?
+        })
+    }
+}
+
+impl Ticket {
+    fn find_errors(&self, rules: &Vec<Rule>) -> Option<usize> {
+        let mut errors: usize = 0;
+        for value in self.values.iter() {
+            if !rules.iter().any(|r| r.verify(*value)) {
+                errors += value;
+            }
+        }
+        if errors > 0 {
+            Some(errors)
+        } else {
+            None
+        }
+    }
+}
+
+fn parse_tickets(section: &str, header: &str) -> Result<Vec<Ticket>, ParseError> {
+    let mut lines = input::lines(section);
+    match lines.next() {
+        Some(line) if line == header => (),
+        other => return Err(ParseError::new(other.unwrap_or(""), format!("Expected '{}'", header)).on_line(1))
+    }
+    lines.enumerate().map(|(index, line)| line.parse().map_err(|e: ParseError| e.on_line(index + 2))).collect()
+}
+
+pub fn solve(text: &str) -> Result<usize, ParseError> {
+    let sections: Vec<&str> = input::blocks(text);
+    if sections.len() != 3 {
+        return Err(ParseError::new("", "Expected 3 sections: rules, your ticket and nearby tickets".to_string()));
+    }
+    let rules: Vec<Rule> = input::parse_lines(sections[0]).map_err(|e| e.on_line(input::line_number(text, sections[0])))?;
+    let _my_ticket: Vec<Ticket> = parse_tickets(sections[1], "your ticket:").map_err(|e| e.on_line(input::line_number(text, sections[1])))?;
+    let tickets: Vec<Ticket> = parse_tickets(sections[2], "nearby tickets:").map_err(|e| e.on_line(input::line_number(text, sections[2])))?;
+    if log::enabled(Level::Debug) { // used for part2
+        let valid: Vec<&Ticket> = tickets.iter().filter(|ticket| ticket.find_errors(&rules).is_none()).collect();
+        let errors: Vec<usize> = tickets.iter().filter_map(|ticket| ticket.find_errors(&rules)).collect();
+        debug!("Valid tickets: {}", valid.len());
+        debug!("Sum of {} errors is {}", errors.len(), errors.iter().sum::<usize>());
+        let bad_indicies: Vec<usize> = tickets.iter().enumerate().filter(|(_i,ticket)| ticket.find_errors(&rules).is_some()).map(|(i,_t)| i).collect();
+        debug!("Bad indicies: {:?}", bad_indicies);
+    }
+    Ok(tickets.iter().filter_map(|ticket| ticket.find_errors(&rules)).sum())
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use regex::Regex;
+use lazy_static::lazy_static;
+use std::str::FromStr;
+use common::input;
+use common::error::{self, Error, ParseError};
+use common::number::Number;
+use common::{debug, info};
+
+#[derive(Clone)]
+struct Rule {
+    field: String,
+    min1: usize,
+    max1: usize,
+    min2: usize,
+    max2: usize
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RULE_REGEX: Regex = Regex::new("^(.*): (\\d*)-(\\d*) or (\\d*)-(\\d*)$").unwrap();
+        }
+        match RULE_REGEX.captures(line) {
+            Some(rule_match) => Ok(Rule {
+                field: rule_match.get(1).unwrap().as_str().to_string(),
+                min1: error::parse_part(line, rule_match.get(2).unwrap().as_str(), "a number")?,
+                max1: error::parse_part(line, rule_match.get(3).unwrap().as_str(), "a number")?,
+                min2: error::parse_part(line, rule_match.get(4).unwrap().as_str(), "a number")?,
+                max2: error::parse_part(line, rule_match.get(5).unwrap().as_str(), "a number")?,
+            }),
+            None => Err(ParseError::new(line, "Expected '<field>: <min>-<max> or <min>-<max>'".to_string()))
+        }
+    }
+}
+
+impl Rule {
+    fn verify(&self, value: usize) -> bool {
+        (value >= self.min1 && value <= self.max1) || (value >= self.min2 && value <= self.max2)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Ticket {
+    values: Vec<usize>
+}
+
+impl FromStr for Ticket {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        Ok(Ticket {
+            values: line.split(",").map(|s| error::parse_part(line, s, "a number")).collect::<Result<_, _>>()?
+        })
+    }
+}
+
+impl Ticket {
+    fn find_errors(&self, rules: &Vec<Rule>) -> Option<usize> {
+        let mut errors: usize = 0;
+        let mut any_errors = false;
+        for value in self.values.iter() {
+            if !rules.iter().any(|r| r.verify(*value)) {
+                errors += value;
+                any_errors = true;
+            }
+        }
+        if any_errors {
+            Some(errors)
+        } else {
+            None
+        }
+    }
+}
+
+fn parse_tickets(section: &str, header: &str) -> Result<Vec<Ticket>, ParseError> {
+    let mut lines = input::lines(section);
+    match lines.next() {
+        Some(line) if line == header => (),
+        other => return Err(ParseError::new(other.unwrap_or(""), format!("Expected '{}'", header)).on_line(1))
+    }
+    lines.enumerate().map(|(index, line)| line.parse().map_err(|e: ParseError| e.on_line(index + 2))).collect()
+}
+
+pub fn solve(text: &str) -> Result<Number, Error> {
+    let sections: Vec<&str> = input::blocks(text);
+    if sections.len() != 3 {
+        return Err(ParseError::new("", "Expected 3 sections: rules, your ticket and nearby tickets".to_string()).into());
+    }
+    let rules: Vec<Rule> = input::parse_lines(sections[0]).map_err(|e| e.on_line(input::line_number(text, sections[0])))?;
+    let my_ticket: Ticket = match parse_tickets(sections[1], "your ticket:").map_err(|e| e.on_line(input::line_number(text, sections[1])))?.pop() {
+        Some(ticket) => ticket,
+        None => return Err(ParseError::new("your ticket:", "Expected your ticket on the following line".to_string()).on_line(input::line_number(text, sections[1])).into())
+    };
+    debug!("My ticket: {:?}", my_ticket);
+    let tickets: Vec<Ticket> = parse_tickets(sections[2], "nearby tickets:").map_err(|e| e.on_line(input::line_number(text, sections[2])))?;
+    debug!("Total nearby tickets: {}", tickets.len());
+    let valid: Vec<&Ticket> = tickets.iter().filter(|ticket| ticket.find_errors(&rules).is_none()).collect();
+    debug!("Valid nearby tickets: {}", valid.len());
+    if let Some(ticket) = valid.iter().find(|ticket| ticket.values.len() != my_ticket.values.len()) {
+        return Err(Error::Solve(format!("A nearby ticket has {} values but yours has {}", ticket.values.len(), my_ticket.values.len())));
+    }
+    let field_values: Vec<Vec<usize>> = transpose(valid.iter().map(|t| t.values.clone()).collect::<Vec<Vec<usize>>>());
+    let ordered_rules = match_fields(&field_values, &rules);
+    let mut result = Number::one();
+    for (index, rule) in ordered_rules {
+        debug!("Field: {}, My Ticket Value: {}", rule.field, my_ticket.values[index]);
+        if rule.field.starts_with("departure") {
+            result *= my_ticket.values[index];
+        }
+    }
+    Ok(result)
+}
+
+fn match_fields(field_values: &Vec<Vec<usize>>, rules: &Vec<Rule>) -> HashMap<usize,Rule> {
+    let mut remaining_rules = rules.clone();
+    let mut ordered_rules: HashMap<usize,Rule> = HashMap::new();
+    let mut changes: bool = true;
+    while remaining_rules.len() > 0 && changes {
+        changes = false;
+        debug!("Matching fields: {} remaining", remaining_rules.len());
+        for (index, values) in field_values.iter().enumerate() {
+            if let Entry::Vacant(entry) = ordered_rules.entry(index) {
+                let candidate_indices: Vec<usize> = remaining_rules.iter().enumerate().filter(|(_i,r)| values.iter().all(|v| r.verify(*v))).map(|(i,_r)| i).collect();
+                if candidate_indices.len() == 1 {
+                    changes = true;
+                    entry.insert(remaining_rules.remove(candidate_indices[0]));
+                }
+            }
+        }
+    }
+    if remaining_rules.len() > 0 {
+        info!("Failed: {} fields not matched: {:?}", remaining_rules.len(), remaining_rules.iter().map(|r| r.field.to_string()).collect::<Vec<String>>());
+    }
+    ordered_rules
+}
+//source: https://stackoverflow.com/questions/64498617/how-to-transpose-a-vector-of-vectors-in-rust
+fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    if v.is_empty() {
+        return Vec::new();
+    }
+    (0..v[0].len())
+        .map(|i| v.iter().map(|inner| inner[i].clone()).collect::<Vec<T>>())
+        .collect()
+}
@@ -0,0 +1,11 @@
+use common::gen::{Generated, Rng};
+use common::grid::Grid;
+
+/// An initial slice of size cubes square (8 by default), about half of them active, with no known
+/// answers, since the only way to find out which cubes are active after 6 cycles is to run them
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(8).max(1);
+    let slice: Grid<bool> = Grid::new(size, size, false).map(|_, _| rng.chance(1, 2));
+    Generated::new(slice.render(|active| if *active { '#' } else { '.' }))
+        .param(None, "cycles", 6)
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+use std::str::FromStr;
+use common::automaton::{Automaton, Rule};
+use common::checkpoint::{Checkpoint, Checkpointer};
+use common::error::{self, Error, ParseError};
+use common::grid::Grid;
+use common::render::{self, Picture, Rgb};
+use common::run::Task;
+use common::trace;
+
+#[derive(Hash, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+struct Point {
+    x: isize,
+    y: isize,
+    z: isize,
+    w: isize
+}
+
+#[derive(Copy, Clone)]
+enum Cube {
+    Active,
+    Inactive
+}
+
+impl Cube {
+    fn from_char(c: &char) -> Option<Self> {
+        match *c {
+            '#' => Some(Cube::Active),
+            '.' => Some(Cube::Inactive),
+            _ => None
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Cube::Active => '#',
+            Cube::Inactive => '.',
+        }
+    }
+
+    fn colour(&self) -> Rgb {
+        match self {
+            Cube::Active => Rgb(60, 120, 220),
+            Cube::Inactive => Rgb(230, 230, 230)
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        match self {
+            Cube::Active => true,
+            _ => false
+        }
+    }
+}
+
+impl Point {
+    fn adjacent(&self) -> Vec<Point> {
+        let mut adjacent = Vec::new();
+        for x_offset in -1..2 {
+            for y_offset in -1..2 {
+                for z_offset in -1..2 {
+                    for w_offset in -1..2 {
+                        if x_offset != 0 || y_offset != 0 || z_offset != 0 || w_offset != 0 {
+                            adjacent.push(Point {
+                                x: self.x + x_offset,
+                                y: self.y + y_offset,
+                                z: self.z + z_offset,
+                                w: self.w + w_offset
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        adjacent
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{},{}", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = text.split(',').collect();
+        if parts.len() != 4 {
+            return Err(ParseError::new(text, "Expected <x>,<y>,<z>,<w>".to_string()));
+        }
+        Ok(Point {
+            x: error::parse_part(text, parts[0], "a coordinate")?,
+            y: error::parse_part(text, parts[1], "a coordinate")?,
+            z: error::parse_part(text, parts[2], "a coordinate")?,
+            w: error::parse_part(text, parts[3], "a coordinate")?
+        })
+    }
+}
+
+struct PocketDimension {
+    space: Automaton<Point, fn(&Point) -> Vec<Point>>
+}
+
+impl PocketDimension {
+    fn cycle(&mut self) {
+        self.space.step();
+    }
+
+    fn plane(&self, z_plane: isize, w_plane: isize) -> Grid<Cube> {
+        let (min,max) = self.get_bounds();
+        let active_points = self.space.live();
+        Grid::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, Cube::Inactive)
+            .map(|(y,x), _| if active_points.contains(&Point {x: x as isize + min.x, y: y as isize + min.y, z: z_plane, w: w_plane}) {
+                Cube::Active
+            } else {
+                Cube::Inactive
+            })
+    }
+
+    fn to_plane(&self, z_plane: isize, w_plane: isize) -> String {
+        self.plane(z_plane, w_plane).render(|cube| cube.to_char())
+    }
+
+    /// Each z plane side by side, with a row of them for each w
+    fn to_picture(&self) -> Picture {
+        let (min,max) = self.get_bounds();
+        let rows: Vec<Vec<Grid<Rgb>>> = (min.w..(max.w+1))
+            .map(|w| (min.z..(max.z+1)).map(|z| self.plane(z, w).map(|_position, cube| cube.colour())).collect())
+            .collect();
+        Picture::mosaic(&rows, Rgb::WHITE)
+    }
+
+    fn to_string(&self) -> String {
+        let (min,max) = self.get_bounds();
+        let mut s = String::new();
+        for w in min.w..(max.w+1) {
+            for z in min.z..(max.z+1) {
+                s.push_str(&format!("z={},w={}",z,w));
+                s.push('\n');
+                s.push_str(&self.to_plane(z,w));
+                s.push('\n');
+            }
+        }
+        s
+    }
+
+    fn get_bounds(&self) -> (Point, Point) {
+        let active_points = self.space.live();
+        let min = Point {
+            x: active_points.iter().map(|p| p.x).min().unwrap_or(0),
+            y: active_points.iter().map(|p| p.y).min().unwrap_or(0),
+            z: active_points.iter().map(|p| p.z).min().unwrap_or(0),
+            w: active_points.iter().map(|p| p.w).min().unwrap_or(0)
+        };
+        let max = Point {
+            x: active_points.iter().map(|p| p.x).max().unwrap_or(0),
+            y: active_points.iter().map(|p| p.y).max().unwrap_or(0),
+            z: active_points.iter().map(|p| p.z).max().unwrap_or(0),
+            w: active_points.iter().map(|p| p.w).max().unwrap_or(0)
+        };
+        (min,max)
+    }
+
+    fn new(plane: &Grid<Cube>) -> Self {
+        let active = plane.positions().filter(|(_position, cube)| cube.is_active())
+            .map(|((y,x), _cube)| Point { x: x as isize, y: y as isize, z: 0, w: 0 });
+        PocketDimension::from_active(active, 0)
+    }
+
+    fn from_active<I: IntoIterator<Item = Point>>(active: I, cycle: usize) -> Self {
+        // inactive cubes become active with exactly 3 active neighbours, and active cubes stay active with 2 or 3
+        PocketDimension {
+            space: Automaton::new(active, Point::adjacent as fn(&Point) -> Vec<Point>, Rule::new(&[3], &[2, 3])).at_generation(cycle)
+        }
+    }
+
+    fn save(&self, checkpoint: &mut Checkpoint) {
+        checkpoint.set_list("active", self.space.live().iter());
+    }
+
+    fn restore(checkpoint: &Checkpoint) -> Result<Self, Error> {
+        Ok(PocketDimension::from_active(checkpoint.get_list::<Point>("active")?, checkpoint.progress()?))
+    }
+}
+
+pub fn solve(text: &str, cycles: usize) -> Result<usize, Error> {
+    let mut checkpointer = Checkpointer::new("day17 part2", text);
+    let mut space = match checkpointer.resume(Some(cycles))? {
+        Some(checkpoint) => PocketDimension::restore(&checkpoint)?,
+        None => {
+            let initial_plane = Grid::parse(text, "'#' or '.'", |c| Cube::from_char(&c))?;
+            let space = PocketDimension::new(&initial_plane);
+            trace!("Before any cycles:\n\n{}", space.to_string());
+            space
+        }
+    };
+    render::save_frame(space.space.generation(), || space.to_picture())?;
+    let mut task = Task::new("running cycles").check_every(1);
+    while space.space.generation() < cycles {
+        checkpointer.tick(&mut task, space.space.generation(), |checkpoint| space.save(checkpoint))?;
+        space.cycle();
+        trace!("After {} cycles:\n\n{}", space.space.generation(), space.to_string());
+        render::save_frame(space.space.generation(), || space.to_picture())?;
+    }
+    render::save(|| space.to_picture())?;
+    Ok(space.space.count())
+}
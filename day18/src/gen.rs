@@ -0,0 +1,57 @@
+use common::gen::{Generated, Rng};
+
+/// Any line whose value would be larger than this is replaced, to keep the sum well within 64 bits
+const LARGEST: i128 = 1_000_000_000_000;
+
+/// Homework of size expressions (375 by default), with brackets nested up to 2 deep, where the value of
+/// each is worked out under both sets of rules while it is being built
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(375);
+    let mut lines = Vec::new();
+    let mut same_precedence = 0;
+    let mut addition_first = 0;
+    while lines.len() < size {
+        let (text, value1, value2) = expression(rng, 0);
+        if value1 <= LARGEST && value2 <= LARGEST {
+            lines.push(text);
+            same_precedence += value1;
+            addition_first += value2;
+        }
+    }
+    Generated::new(lines.join("\n"))
+        .answer(1, same_precedence as isize)
+        .answer(2, addition_first as isize)
+}
+
+/// An expression, along with its value when evaluated left to right, and when addition comes first
+/// (either of which saturates rather than overflowing, as it will be replaced anyway)
+fn expression(rng: &mut Rng, depth: usize) -> (String, i128, i128) {
+    let mut text = String::new();
+    let mut value1 = 0;
+    let mut product2: i128 = 1;
+    let mut sum2 = 0;
+    for index in 0..rng.range(2, 4) {
+        let (term, term1, term2) = if depth < 2 && rng.chance(1, 4) {
+            let (inner, inner1, inner2) = expression(rng, depth + 1);
+            (format!("({})", inner), inner1, inner2)
+        } else {
+            let number = rng.range(1, 9) as i128;
+            (number.to_string(), number, number)
+        };
+        if index == 0 {
+            value1 = term1;
+            sum2 = term2;
+        } else if rng.chance(1, 2) {
+            text.push_str(" + ");
+            value1 = value1.saturating_add(term1);
+            sum2 = sum2.saturating_add(term2);
+        } else {
+            text.push_str(" * ");
+            value1 = value1.saturating_mul(term1);
+            product2 = product2.saturating_mul(sum2);
+            sum2 = term2;
+        }
+        text.push_str(&term);
+    }
+    (text, value1, product2.saturating_mul(sum2))
+}
@@ -0,0 +1,78 @@
+use std::str::Chars;
+use common::input;
+use common::error::ParseError;
+use common::number::Number;
+use common::{debug, trace};
+
+const DIGITS: [char; 10] = ['0','1','2','3','4','5','6','7','8','9'];
+
+fn evaluate(expression: &str) -> Result<Number,String> {
+    if expression.chars().all(|c| DIGITS.contains(&c)) {
+        // parse number
+        trace!("Evaluating number: {}", expression);
+        match expression.parse() {
+            Ok(number) => Ok(number),
+            Err(_) => Err(format!("Not a number: {}", expression))
+        }
+    } else {
+        // parse operation
+        trace!("Evaluating operation: {}", expression);
+        let mut remaining = expression.chars();
+        let first_operand = read_expression(&mut remaining)?;
+        if first_operand == expression {
+            return Err(format!("Expected an operator: {}", expression)); // otherwise this would recurse forever
+        }
+        let mut result: Number = evaluate(&first_operand)?;
+        while let Some(operator) = remaining.next() {
+            if remaining.next() != Some(' ') { // consume space
+                return Err(format!("Expected a space after operator: {}", operator));
+            }
+            let next_operand = read_expression(&mut remaining)?;
+            match operator {
+                '+' => result += evaluate(&next_operand)?,
+                '*' => result *= evaluate(&next_operand)?,
+                _ => return Err(format!("Invalid operator: {}", operator))
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn read_expression(iter: &mut Chars) -> Result<String,String> { // reads digits until a space, or everything inside (brackets)
+    let mut brackets: usize = 0;
+    let mut s = String::new();
+    while let Some(c) = iter.next() {
+        if c == ' ' && brackets == 0 {
+            break;
+        } else if c == '(' {
+            brackets += 1;
+            if brackets == 1 {
+                continue; // dont include the first open bracket
+            }
+        } else if c == ')' {
+            if brackets == 0 {
+                return Err("Unmatched close bracket".to_string());
+            }
+            brackets -= 1;
+            if brackets == 0 {
+                continue; // dont include the last close bracket
+            }
+        }
+        s.push(c);
+    }
+    if brackets > 0 {
+        return Err("Unmatched open bracket".to_string());
+    }
+    Ok(s)
+}
+
+pub fn solve(text: &str) -> Result<Number, ParseError> {
+    let expressions: Vec<&str> = input::lines(text).collect();
+    let mut sum = Number::zero();
+    for (index, expression) in expressions.iter().enumerate() {
+        let result = evaluate(expression).map_err(|e| ParseError::new(expression, e).on_line(index + 1))?;
+        debug!("{} = {}", expression, result);
+        sum += result;
+    }
+    Ok(sum)
+}
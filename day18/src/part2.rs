@@ -1,26 +1,26 @@
 use std::str::Chars;
-use std::env;
-use std::fs;
-
-const NEW_LINE: &str = "\r\n";
+use common::input;
+use common::error::ParseError;
+use common::number::Number;
+use common::{debug, trace};
 
 const DIGITS: [char; 10] = ['0','1','2','3','4','5','6','7','8','9'];
 
-fn evaluate(expression: &str) -> Result<isize,String> {
+fn evaluate(expression: &str) -> Result<Number,String> {
     if expression.chars().all(|c| DIGITS.contains(&c)) {
         // parse number
-        //println!("Evaluating number: {}", expression);
+        trace!("Evaluating number: {}", expression);
         match expression.parse() {
             Ok(number) => Ok(number),
             Err(_) => Err(format!("Not a number: {}", expression))
         }
     } else {
         // parse operation
-        //println!("Evaluating operation: {}", expression);
+        trace!("Evaluating operation: {}", expression);
         let exp_after_addition: String = perform_addition(expression)?;
-        //println!("Expression after addition: {}", exp_after_addition);
+        trace!("Expression after addition: {}", exp_after_addition);
         let exp_after_multiplication: String = perform_multiplication(&exp_after_addition)?;
-        //println!("Expression after multiplication: {}", exp_after_multiplication);
+        trace!("Expression after multiplication: {}", exp_after_multiplication);
         evaluate(&exp_after_multiplication)
     }
 }
@@ -28,11 +28,16 @@ fn evaluate(expression: &str) -> Result<isize,String> {
 fn perform_addition(expression: &str) -> Result<String,String> {
     let mut s = String::new();
     let mut remaining = expression.chars();
-    let first_operand = read_expression(&mut remaining);
+    let first_operand = read_expression(&mut remaining)?;
+    if first_operand == expression {
+        return Err(format!("Expected an operator: {}", expression)); // otherwise this would recurse forever
+    }
     let mut result = evaluate(&first_operand)?;
     while let Some(operator) = remaining.next() {
-        assert_eq!(remaining.next().unwrap(),' '); // consume space
-        let next_operand = read_expression(&mut remaining);
+        if remaining.next() != Some(' ') { // consume space
+            return Err(format!("Expected a space after operator: {}", operator));
+        }
+        let next_operand = read_expression(&mut remaining)?;
         match operator {
             '+' => result += evaluate(&next_operand)?,
             '*' => {
@@ -50,11 +55,13 @@ fn perform_addition(expression: &str) -> Result<String,String> {
 fn perform_multiplication(expression: &str) -> Result<String,String> {
     let mut s = String::new();
     let mut remaining = expression.chars();
-    let first_operand = read_expression(&mut remaining);
+    let first_operand = read_expression(&mut remaining)?;
     let mut result = evaluate(&first_operand)?;
     while let Some(operator) = remaining.next() {
-        assert_eq!(remaining.next().unwrap(),' '); // consume space
-        let next_operand = read_expression(&mut remaining);
+        if remaining.next() != Some(' ') { // consume space
+            return Err(format!("Expected a space after operator: {}", operator));
+        }
+        let next_operand = read_expression(&mut remaining)?;
         match operator {
             '+' => {
                 s.push_str(&result.to_string());
@@ -69,7 +76,7 @@ fn perform_multiplication(expression: &str) -> Result<String,String> {
     Ok(s)
 }
 
-fn read_expression(iter: &mut Chars) -> String { // reads digits until a space, or everything inside (brackets)
+fn read_expression(iter: &mut Chars) -> Result<String,String> { // reads digits until a space, or everything inside (brackets)
     let mut brackets: usize = 0;
     let mut s = String::new();
     while let Some(c) = iter.next() {
@@ -81,6 +88,9 @@ fn read_expression(iter: &mut Chars) -> String { // reads digits until a space,
                 continue; // dont include the first open bracket
             }
         } else if c == ')' {
+            if brackets == 0 {
+                return Err("Unmatched close bracket".to_string());
+            }
             brackets -= 1;
             if brackets == 0 {
                 continue; // dont include the last close bracket
@@ -88,24 +98,19 @@ fn read_expression(iter: &mut Chars) -> String { // reads digits until a space,
         }
         s.push(c);
     }
-    s
+    if brackets > 0 {
+        return Err("Unmatched open bracket".to_string());
+    }
+    Ok(s)
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let expressions: Vec<&str> = text.split(NEW_LINE).collect();
-        let mut sum: isize = 0;
-        for expression in expressions {
-            let result = evaluate(expression).unwrap();
-            println!("{} = {}", expression, result);
-            sum += result;
-        }
-        println!("Sum of results: {}", sum);
-    } else {
-        println!("Please provide 1 argument: Filename");
+pub fn solve(text: &str) -> Result<Number, ParseError> {
+    let expressions: Vec<&str> = input::lines(text).collect();
+    let mut sum = Number::zero();
+    for (index, expression) in expressions.iter().enumerate() {
+        let result = evaluate(expression).map_err(|e| ParseError::new(expression, e).on_line(index + 1))?;
+        debug!("{} = {}", expression, result);
+        sum += result;
     }
-}
\ No newline at end of file
+    Ok(sum)
+}
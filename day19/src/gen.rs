@@ -0,0 +1,79 @@
+use common::gen::{Generated, Rng};
+
+/// Rules where 0 is 8 11, 8 is 42 and 11 is 42 31 (like the puzzle, so that they can be replaced for
+/// part 2) and 42 and 31 each match chunks of the same length, starting with 'a' and 'b' respectively,
+/// followed by a random pattern of letters and wildcards, along with size messages (400 by default)
+/// which are a mix of 42 42 31, other numbers of chunks, and either of those with a letter changed.
+/// Only part 1 has a known answer, as part 2 needs a different input with 8 and 11 replaced.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(400);
+    let tail: Vec<Option<char>> = (0..rng.range(4, 7)).map(|_| match rng.below(3) {
+        0 => Some('a'),
+        1 => Some('b'),
+        _ => None
+    }).collect();
+    // number the other rules randomly, avoiding those with fixed numbers
+    let mut numbers: Vec<usize> = (1..(tail.len() + 20)).filter(|n| ![8, 11, 31, 42].contains(n)).collect();
+    rng.shuffle(&mut numbers);
+    let (a, b, any) = (numbers[0], numbers[1], numbers[2]);
+    let chain = &numbers[3..(3 + tail.len())]; // chain[i] matches the tail from i onwards
+    let mut rules = vec![
+        "0: 8 11".to_string(),
+        "8: 42".to_string(),
+        "11: 42 31".to_string(),
+        format!("42: {} {}", a, chain[0]),
+        format!("31: {} {}", b, chain[0]),
+        format!("{}: \"a\"", a),
+        format!("{}: \"b\"", b),
+        format!("{}: {} | {}", any, a, b)
+    ];
+    for (i, letter) in tail.iter().enumerate() {
+        let first = match letter {
+            Some('a') => a,
+            Some(_) => b,
+            None => any
+        };
+        rules.push(match chain.get(i + 1) {
+            Some(rest) => format!("{}: {} {}", chain[i], first, rest),
+            None => format!("{}: {}", chain[i], first)
+        });
+    }
+    rng.shuffle(&mut rules);
+    let chunk = |rng: &mut Rng, first: char| -> String {
+        let mut text = first.to_string();
+        for letter in tail.iter() {
+            text.push(letter.unwrap_or_else(|| if rng.chance(1, 2) { 'a' } else { 'b' }));
+        }
+        text
+    };
+    let mut messages: Vec<String> = Vec::new();
+    let mut valid: usize = 0;
+    while messages.len() < size {
+        let firsts: Vec<char> = match rng.below(3) {
+            0 => vec!['a', 'a', 'b'],
+            _ => (0..rng.range(1, 5)).map(|_| if rng.chance(2, 3) { 'a' } else { 'b' }).collect()
+        };
+        let mut message: Vec<char> = firsts.iter().flat_map(|first| chunk(rng, *first).chars().collect::<Vec<char>>()).collect();
+        if rng.chance(1, 4) {
+            let index = rng.below(message.len());
+            message[index] = if message[index] == 'a' { 'b' } else { 'a' };
+        }
+        let message: String = message.into_iter().collect();
+        if matches(&message, &tail) {
+            valid += 1;
+        }
+        messages.push(message);
+    }
+    Generated::new(format!("{}\n\n{}", rules.join("\n"), messages.join("\n")))
+        .answer(1, valid)
+}
+
+/// Whether a message matches rule 0, ie. 42 42 31
+fn matches(message: &str, tail: &Vec<Option<char>>) -> bool {
+    let chars: Vec<char> = message.chars().collect();
+    let length = tail.len() + 1;
+    chars.len() == 3 * length && ['a', 'a', 'b'].iter().enumerate().all(|(c, first)| {
+        let chunk = &chars[(c * length)..((c + 1) * length)];
+        chunk[0] == *first && tail.iter().zip(chunk[1..].iter()).all(|(letter, actual)| letter.map_or(true, |l| l == *actual))
+    })
+}
@@ -0,0 +1,13 @@
+pub mod gen;
+pub mod rules;
+
+use common::answer::Answer;
+use common::error::Error;
+
+pub fn part1(text: &str) -> Result<Answer, Error> {
+    Ok(rules::solve(text)?.into())
+}
+
+pub fn part2(text: &str) -> Result<Answer, Error> {
+    Ok(rules::solve(text)?.into()) // part2 differs only by input
+}
@@ -0,0 +1,24 @@
+use std::env;
+use common::answer::Solution;
+use common::input;
+use common::log;
+use common::output;
+use common::run;
+use day19::part1;
+
+const USAGE: &str = "Please provide 1 argument: Filename [--timeout <seconds>] [--format text|json] [-q|-v|-vv]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    log::take_verbosity(&mut args);
+    let format = output::or_usage(output::take_format(&mut args), USAGE);
+    output::or_usage(run::take_flags(&mut args), USAGE);
+    if args.len() == 2 {
+        let filename = &args[1];
+        let text = input::read(&filename)
+            .expect(&format!("Error reading from {}", filename));
+        output::print(format, 19, None, filename, part1(&text).map(Solution::from));
+    } else {
+        println!("{}", USAGE);
+    }
+}
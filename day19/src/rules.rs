@@ -1,11 +1,8 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 use std::str::FromStr;
-use std::num::ParseIntError;
-use std::char::ParseCharError;
-
-const NEW_LINE: &str = "\r\n";
+use common::input;
+use common::error::{self, ParseError};
+use common::debug;
 
 enum Rule {
     SingleChar(char),
@@ -21,15 +18,22 @@ impl FromStr for Rule {
             Ok(Rule::SingleChar(line.chars().nth(1).unwrap()))
         } else if line.contains("|") {
             let options: Vec<&str> = line.split("|").collect();
-            Ok(Rule::Options(split_series(options[0]), split_series(options[1])))
+            if options.len() != 2 {
+                return Err(ParseError::new(line, "Expected exactly 2 options separated by '|'".to_string()));
+            }
+            Ok(Rule::Options(split_series(line, options[0])?, split_series(line, options[1])?))
         } else {
-            Ok(Rule::Series(split_series(line)))
+            Ok(Rule::Series(split_series(line, line)?))
         }
     }
 }
 
-fn split_series(list: &str) -> Vec<usize> {
-    list.split(' ').filter(|s| s.len() != 0).map(|s| s.parse().unwrap()).collect()
+fn split_series(line: &str, list: &str) -> Result<Vec<usize>, ParseError> {
+    let series: Vec<usize> = list.split(' ').filter(|s| s.len() != 0).map(|s| error::parse_part(line, s, "a rule number")).collect::<Result<_, _>>()?;
+    if series.len() == 0 {
+        return Err(ParseError::at(line, list, "Expected at least one rule number".to_string()));
+    }
+    Ok(series)
 }
 
 struct Rules {
@@ -41,14 +45,14 @@ impl FromStr for Rules {
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
         let mut rules = HashMap::new();
-        for line in text.split(NEW_LINE) {
+        for (line_index, line) in input::lines(text).enumerate() {
             let parts: Vec<&str> = line.split(": ").collect();
             if parts.len() == 2 {
-                let index: usize = parts[0].parse()?;
-                let rule: Rule = parts[1].parse()?;
+                let index: usize = error::parse_part(line, parts[0], "a rule number").map_err(|e| e.on_line(line_index + 1))?;
+                let rule: Rule = parts[1].parse().map_err(|e: ParseError| e.within(line, parts[1]).on_line(line_index + 1))?;
                 rules.insert(index, rule);
             } else {
-                return Err(ParseError::Other("Invalid rule.".to_string()));
+                return Err(ParseError::new(line, "Expected '<number>: <rule>'".to_string()).on_line(line_index + 1));
             }
         }
         Ok(Rules {
@@ -57,25 +61,6 @@ impl FromStr for Rules {
     }
 }
 
-#[derive(Debug)]
-enum ParseError {
-    Int(ParseIntError),
-    Char(ParseCharError),
-    Other(String)
-}
-
-impl From<ParseIntError> for ParseError {
-    fn from(e: ParseIntError) -> Self {
-        ParseError::Int(e)
-    }
-}
-
-impl From<ParseCharError> for ParseError {
-    fn from(e: ParseCharError) -> Self {
-        ParseError::Char(e)
-    }
-}
-
 enum VerifyResult {
     Success(Vec<String>), // remaining strings after successful matches
     Failure,
@@ -137,29 +122,24 @@ impl Rules {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let sections: Vec<&str> = text.split("\r\n\r\n").collect();
-        let rules: Rules = sections[0].parse().unwrap();
-        let messages: Vec<&str> = sections[1].split(NEW_LINE).collect();
-        let mut count: usize = 0;
-        for message in messages {
-            match rules.verify_root(message) {
-                Ok(result) => {
-                    println!("Message: {} = {}", message, result);
-                    if result {
-                        count += 1;
-                    }
-                },
-                Err(error) => println!("Error: {}", error)
-            };
-        }
-        println!("Results: {}", count);
-    } else {
-        println!("Please provide 1 argument: Filename");
+pub fn solve(text: &str) -> Result<usize, ParseError> {
+    let sections: Vec<&str> = input::blocks(text);
+    if sections.len() != 2 {
+        return Err(ParseError::new("", "Expected 2 sections: rules and messages".to_string()));
     }
-}
\ No newline at end of file
+    let rules: Rules = sections[0].parse().map_err(|e: ParseError| e.on_line(input::line_number(text, sections[0])))?;
+    let messages: Vec<&str> = input::lines(sections[1]).collect();
+    let mut count: usize = 0;
+    for message in messages {
+        match rules.verify_root(message) {
+            Ok(result) => {
+                debug!("Message: {} = {}", message, result);
+                if result {
+                    count += 1;
+                }
+            },
+            Err(error) => debug!("Error: {}", error)
+        };
+    }
+    Ok(count)
+}
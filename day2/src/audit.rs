@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use common::csv;
+use common::input;
+use common::error::ParseError;
+use common::{debug, info};
+use crate::policy::{Count, PasswordCheck, Policy, Positions};
+
+/// How an audit is printed
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Format {
+    #[default]
+    Table,
+    Csv
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Format should be table or csv: {}", s))
+        }
+    }
+}
+
+/// How many of a group of lines are valid under each reading of their policy
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Tally {
+    lines: usize,
+    count_valid: usize,
+    positions_valid: usize,
+    both_valid: usize
+}
+
+impl Tally {
+    fn add(&mut self, by_count: bool, by_positions: bool) {
+        self.lines += 1;
+        if by_count {
+            self.count_valid += 1;
+        }
+        if by_positions {
+            self.positions_valid += 1;
+        }
+        if by_count && by_positions {
+            self.both_valid += 1;
+        }
+    }
+
+    fn count_failures(&self) -> usize {
+        self.lines - self.count_valid
+    }
+
+    fn positions_failures(&self) -> usize {
+        self.lines - self.positions_valid
+    }
+
+    /// Valid when the policy is read as a count (part 1), but not as positions (part 2)
+    fn count_only(&self) -> usize {
+        self.count_valid - self.both_valid
+    }
+
+    fn positions_only(&self) -> usize {
+        self.positions_valid - self.both_valid
+    }
+}
+
+/// The percentage of lines which a number of them is
+fn rate(failures: usize, lines: usize) -> f64 {
+    if lines == 0 {
+        0.0
+    } else {
+        failures as f64 * 100.0 / lines as f64
+    }
+}
+
+/// Statistics of a password database under both readings of its policies, for all of the lines, then
+/// grouped by the letter of each policy, and by its range of numbers
+#[derive(Debug, Clone, PartialEq)]
+pub struct Audit {
+    total: Tally,
+    letters: BTreeMap<char, Tally>,
+    ranges: BTreeMap<(u8, u8), Tally>
+}
+
+pub fn audit(text: &str) -> Result<Audit, ParseError> {
+    let checks: Vec<PasswordCheck> = input::parse_lines(text)?;
+    let mut audit = Audit {
+        total: Tally::default(),
+        letters: BTreeMap::new(),
+        ranges: BTreeMap::new()
+    };
+    for (index, check) in checks.iter().enumerate() {
+        let by_count = Count.check(check).is_ok();
+        let by_positions = Positions.check(check).is_ok();
+        if by_count != by_positions {
+            debug!("Line {} ({}) is only valid by {}", index + 1, check, if by_count { "count" } else { "positions" });
+        }
+        audit.total.add(by_count, by_positions);
+        audit.letters.entry(check.letter).or_default().add(by_count, by_positions);
+        audit.ranges.entry((check.min, check.max)).or_default().add(by_count, by_positions);
+    }
+    info!("{} of {} lines are valid by count, {} by positions and {} by both", audit.total.count_valid, audit.total.lines, audit.total.positions_valid, audit.total.both_valid);
+    Ok(audit)
+}
+
+impl Audit {
+    /// Every group with its tally, starting with all of the lines
+    fn rows(&self) -> Vec<(&'static str, String, &Tally)> {
+        let mut rows = vec![("all", String::new(), &self.total)];
+        rows.extend(self.letters.iter().map(|(letter, tally)| ("letter", letter.to_string(), tally)));
+        rows.extend(self.ranges.iter().map(|((min, max), tally)| ("range", format!("{}-{}", min, max), tally)));
+        rows
+    }
+
+    /// Renders the audit in the given format, where csv is intended to be saved and compared, so its
+    /// fields should not be renamed or reordered
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Table => self.render_table(),
+            Format::Csv => self.render_csv()
+        }
+    }
+
+    fn render_table(&self) -> String {
+        let mut lines = vec![format!("{:<6} {:>6} {:>6} {:>12} {:>7} {:>16} {:>7} {:>11} {:>15}",
+            "Group", "Value", "Lines", "Count fails", "%", "Positions fails", "%", "Count only", "Positions only")];
+        for (group, value, tally) in self.rows() {
+            lines.push(format!("{:<6} {:>6} {:>6} {:>12} {:>7.1} {:>16} {:>7.1} {:>11} {:>15}",
+                group, value, tally.lines,
+                tally.count_failures(), rate(tally.count_failures(), tally.lines),
+                tally.positions_failures(), rate(tally.positions_failures(), tally.lines),
+                tally.count_only(), tally.positions_only()));
+        }
+        lines.join("\n")
+    }
+
+    fn render_csv(&self) -> String {
+        let mut lines = vec!["group,value,lines,count_failures,count_failure_rate,positions_failures,positions_failure_rate,count_only,positions_only".to_string()];
+        for (group, value, tally) in self.rows() {
+            lines.push(format!("{},{},{},{},{:.3},{},{:.3},{},{}",
+                group, csv::field(&value), tally.lines,
+                tally.count_failures(), rate(tally.count_failures(), tally.lines),
+                tally.positions_failures(), rate(tally.positions_failures(), tally.lines),
+                tally.count_only(), tally.positions_only()));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{audit, Format};
+
+    #[test]
+    fn tallies_both_readings() {
+        let audit = audit("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n1-2 a: aa").unwrap();
+        let csv = audit.render(Format::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[1], "all,,4,1,25.000,3,75.000,2,0");
+        assert_eq!(lines[2], "letter,a,2,0,0.000,1,50.000,1,0");
+        assert_eq!(lines[5], "range,1-2,1,0,0.000,1,100.000,1,0");
+        assert_eq!(lines.len(), 8);
+    }
+}
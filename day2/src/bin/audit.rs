@@ -0,0 +1,31 @@
+use std::env;
+use common::input;
+use common::log;
+use common::output;
+use day2::audit::{self, Format};
+
+const USAGE: &str = "Please provide arguments: Filename... [--format table|csv] [-q|-v|-vv]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    log::take_verbosity(&mut args);
+    let format: Format = output::or_usage(output::take_format(&mut args), USAGE);
+    let (files, flags) = input::split_files(&args[1..]);
+    if files.is_empty() || !flags.is_empty() {
+        println!("{}", USAGE);
+        return;
+    }
+    for file in files.iter() {
+        let name = if file == input::STDIN { "stdin" } else { file.as_str() };
+        if files.len() > 1 {
+            println!("{}:", name);
+        }
+        match input::read(file) {
+            Ok(text) => match audit::audit(&text) {
+                Ok(audit) => println!("{}", audit.render(format)),
+                Err(error) => println!("Error: {}", error.in_file(name))
+            },
+            Err(error) => println!("Error: Error reading from {}: {}", name, error)
+        }
+    }
+}
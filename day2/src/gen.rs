@@ -0,0 +1,38 @@
+use common::gen::{Generated, Rng};
+
+/// A password database of size lines (1000 by default), where the letter of each policy appears a
+/// number of times around its range, at random positions
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(1000);
+    let mut lines = Vec::new();
+    let mut valid_by_count: usize = 0;
+    let mut valid_by_position: usize = 0;
+    for _ in 0..size {
+        let letter = (b'a' + rng.below(26) as u8) as char;
+        let min = rng.range(1, 8);
+        let max = rng.range(min + 1, min + 10);
+        let length = rng.range(max, max + 6);
+        let count = rng.range(0, (max + 2).min(length));
+        let mut password: Vec<char> = (0..length).map(|i| if i < count {
+            letter
+        } else {
+            loop {
+                let other = (b'a' + rng.below(26) as u8) as char;
+                if other != letter {
+                    break other;
+                }
+            }
+        }).collect();
+        rng.shuffle(&mut password);
+        if count >= min && count <= max {
+            valid_by_count += 1;
+        }
+        if (password[min - 1] == letter) != (password[max - 1] == letter) {
+            valid_by_position += 1;
+        }
+        lines.push(format!("{}-{} {}: {}", min, max, letter, password.iter().collect::<String>()));
+    }
+    Generated::new(lines.join("\n"))
+        .answer(1, valid_by_count)
+        .answer(2, valid_by_position)
+}
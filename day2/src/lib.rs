@@ -0,0 +1,26 @@
+pub mod audit;
+pub mod gen;
+pub mod policy;
+
+use common::answer::{Answer, Solution};
+use common::error::Error;
+
+/// The number of passwords which follow the policy, which is `count` unless --policy says otherwise
+pub fn part1(text: &str, policy: &str) -> Result<Answer, Error> {
+    Ok(part1_solution(text, policy)?.answer)
+}
+
+/// The answer to part 1 along with every password which doesn't follow the policy, and why
+pub fn part1_solution(text: &str, policy: &str) -> Result<Solution, Error> {
+    policy::solve(text, policy::parse(policy)?.as_ref())
+}
+
+/// The number of passwords which follow the policy, which is `positions` unless --policy says otherwise
+pub fn part2(text: &str, policy: &str) -> Result<Answer, Error> {
+    Ok(part2_solution(text, policy)?.answer)
+}
+
+/// The answer to part 2 along with every password which doesn't follow the policy, and why
+pub fn part2_solution(text: &str, policy: &str) -> Result<Solution, Error> {
+    policy::solve(text, policy::parse(policy)?.as_ref())
+}
@@ -0,0 +1,350 @@
+use std::fmt;
+use std::str::FromStr;
+use regex::Regex;
+use common::input;
+use common::answer::Solution;
+use common::error::{self, Error, ParseError};
+use common::json::Json;
+use common::{debug, info};
+
+/// A line of the password database, which is a password along with the numbers and letter its policy
+/// was given (which each policy reads in its own way)
+pub struct PasswordCheck {
+    pub min: u8,
+    pub max: u8,
+    pub letter: char,
+    pub password: String
+}
+
+impl FromStr for PasswordCheck {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = line.split(" ").collect();
+        if parts.len() != 3 {
+            return Err(ParseError::new(line, "Expected '<min>-<max> <letter>: <password>'".to_string()));
+        }
+        let p0: Vec<&str> = parts[0].split("-").collect();
+        if p0.len() != 2 {
+            return Err(ParseError::at(line, parts[0], "Expected '<min>-<max>'".to_string()));
+        }
+        let p1: Vec<&str> = parts[1].split(":").collect();
+        Ok(PasswordCheck {
+            min: error::parse_part(line, p0[0], "a number")?,
+            max: error::parse_part(line, p0[1], "a number")?,
+            letter: error::parse_part(line, p1[0], "a letter")?,
+            password: parts[2].to_string()
+        })
+    }
+}
+
+impl fmt::Display for PasswordCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{} {}: {}", self.min, self.max, self.letter, self.password)
+    }
+}
+
+impl PasswordCheck {
+    pub fn count(&self) -> usize {
+        self.password.chars().filter(|c| *c == self.letter).count()
+    }
+
+    pub fn has_letter_at(&self, position: u8) -> bool { // positions start from 1, and there is no letter past the end
+        position > 0 && self.password.chars().nth((position-1).into()) == Some(self.letter)
+    }
+}
+
+/// A rule which the password on each line of the database should follow
+pub trait Policy {
+    /// Ok if the password follows the policy, otherwise the reason it doesn't
+    fn check(&self, entry: &PasswordCheck) -> Result<(), String>;
+}
+
+/// The letter appears between min and max times (part 1)
+pub struct Count;
+
+impl Policy for Count {
+    fn check(&self, entry: &PasswordCheck) -> Result<(), String> {
+        let count = entry.count();
+        if count >= entry.min.into() && count <= entry.max.into() {
+            Ok(())
+        } else {
+            Err(format!("'{}' appears {} times rather than {}-{}", entry.letter, count, entry.min, entry.max))
+        }
+    }
+}
+
+/// The letter is at exactly one of the positions min and max, counting from 1 (part 2)
+pub struct Positions;
+
+impl Policy for Positions {
+    fn check(&self, entry: &PasswordCheck) -> Result<(), String> {
+        match (entry.has_letter_at(entry.min), entry.has_letter_at(entry.max)) {
+            (true, true) => Err(format!("'{}' is at both positions {} and {}", entry.letter, entry.min, entry.max)),
+            (false, false) => Err(format!("'{}' is at neither position {} nor {}", entry.letter, entry.min, entry.max)),
+            _ => Ok(())
+        }
+    }
+}
+
+/// The password matches a regex somewhere (anchor it with ^ and $ to match all of it)
+pub struct Matches(Regex);
+
+impl Policy for Matches {
+    fn check(&self, entry: &PasswordCheck) -> Result<(), String> {
+        if self.0.is_match(&entry.password) {
+            Ok(())
+        } else {
+            Err(format!("doesn't match /{}/", self.0))
+        }
+    }
+}
+
+/// The password has at least this many characters
+pub struct MinLength(usize);
+
+impl Policy for MinLength {
+    fn check(&self, entry: &PasswordCheck) -> Result<(), String> {
+        let length = entry.password.chars().count();
+        if length >= self.0 {
+            Ok(())
+        } else {
+            Err(format!("has {} characters rather than at least {}", length, self.0))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Class {
+    Lowercase,
+    Uppercase,
+    Digit,
+    Symbol
+}
+
+impl Class {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'l' => Some(Class::Lowercase),
+            'u' => Some(Class::Uppercase),
+            'd' => Some(Class::Digit),
+            's' => Some(Class::Symbol),
+            _ => None
+        }
+    }
+
+    fn contains(&self, c: char) -> bool {
+        match self {
+            Class::Lowercase => c.is_ascii_lowercase(),
+            Class::Uppercase => c.is_ascii_uppercase(),
+            Class::Digit => c.is_ascii_digit(),
+            Class::Symbol => !c.is_ascii_alphanumeric()
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Class::Lowercase => "lowercase letter",
+            Class::Uppercase => "uppercase letter",
+            Class::Digit => "digit",
+            Class::Symbol => "symbol"
+        }
+    }
+}
+
+/// The password has at least one character of each class
+pub struct Classes(Vec<Class>);
+
+impl Policy for Classes {
+    fn check(&self, entry: &PasswordCheck) -> Result<(), String> {
+        let missing: Vec<&str> = self.0.iter().filter(|class| !entry.password.chars().any(|c| class.contains(c))).map(|class| class.name()).collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("has no {}", missing.join(" or ")))
+        }
+    }
+}
+
+/// Every one of the policies is followed
+pub struct All(Vec<Box<dyn Policy>>);
+
+impl Policy for All {
+    fn check(&self, entry: &PasswordCheck) -> Result<(), String> {
+        let reasons: Vec<String> = self.0.iter().filter_map(|policy| policy.check(entry).err()).collect();
+        if reasons.is_empty() {
+            Ok(())
+        } else {
+            Err(reasons.join(", and "))
+        }
+    }
+}
+
+/// At least one of the policies is followed
+pub struct Any(Vec<Box<dyn Policy>>);
+
+impl Policy for Any {
+    fn check(&self, entry: &PasswordCheck) -> Result<(), String> {
+        let mut reasons = Vec::new();
+        for policy in self.0.iter() {
+            match policy.check(entry) {
+                Ok(()) => return Ok(()),
+                Err(reason) => reasons.push(reason)
+            }
+        }
+        Err(reasons.join(", and "))
+    }
+}
+
+/// Reads a policy like `all(count, min-length:8, any(classes:ud, regex:^[a-m]+$))`, made of:
+/// - `count` or `positions`, which read the numbers and letter of each line as in part 1 or part 2
+/// - `regex:<pattern>`, `min-length:<number>` or `classes:<letters>` (from `l`owercase, `u`ppercase,
+///   `d`igit and `s`ymbol, in ASCII), which only look at the password
+/// - `all(<policy>, ...)` or `any(<policy>, ...)` to combine them
+pub fn parse(spec: &str) -> Result<Box<dyn Policy>, String> {
+    let spec = spec.trim();
+    let invalid = |reason: String| format!("Invalid policy '{}': {}", spec, reason);
+    if let Some(pattern) = spec.strip_prefix("regex:") {
+        return Ok(Box::new(Matches(Regex::new(pattern).map_err(|e| invalid(e.to_string()))?)));
+    }
+    if let Some(length) = spec.strip_prefix("min-length:") {
+        return Ok(Box::new(MinLength(length.parse().map_err(|_| invalid("expected a number of characters".to_string()))?)));
+    }
+    if let Some(letters) = spec.strip_prefix("classes:") {
+        let classes: Option<Vec<Class>> = letters.chars().map(Class::from_char).collect();
+        return match classes {
+            Some(classes) if !classes.is_empty() => Ok(Box::new(Classes(classes))),
+            _ => Err(invalid("expected classes from l, u, d and s".to_string()))
+        };
+    }
+    for (name, all) in [("all(", true), ("any(", false)] {
+        if let Some(inner) = spec.strip_prefix(name) {
+            let inner = inner.strip_suffix(")").ok_or_else(|| invalid("expected a closing bracket".to_string()))?;
+            let policies = split_arguments(inner).map_err(invalid)?.into_iter().map(parse).collect::<Result<Vec<_>, _>>()?;
+            if policies.is_empty() {
+                return Err(invalid("expected at least one policy to combine".to_string()));
+            }
+            return Ok(if all { Box::new(All(policies)) } else { Box::new(Any(policies)) });
+        }
+    }
+    match spec {
+        "count" => Ok(Box::new(Count)),
+        "positions" => Ok(Box::new(Positions)),
+        _ => Err(invalid("expected count, positions, regex:, min-length:, classes:, all(...) or any(...)".to_string()))
+    }
+}
+
+/// Splits on the commas which aren't inside any round brackets, so that a regex can still have them,
+/// where an escaped character (eg. `\)`) and anything in a character class (eg. `[),]`) or a
+/// repetition (eg. `{1,2}`) isn't a bracket, and a `]` or `}` on its own is just a character
+fn split_arguments(text: &str) -> Result<Vec<&str>, String> {
+    let mut arguments = Vec::new();
+    let mut depth: usize = 0;
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            },
+            '[' => {
+                // a ] straight after the [ (or [^) is part of the class, rather than closing it
+                chars.next_if(|(_, c)| *c == '^');
+                chars.next_if(|(_, c)| *c == ']');
+                loop {
+                    match chars.next() {
+                        Some((_, '\\')) => {
+                            chars.next();
+                        },
+                        Some((_, ']')) => break,
+                        Some(_) => (),
+                        None => return Err("unmatched open bracket".to_string())
+                    }
+                }
+            },
+            '{' => {
+                // a repetition like {1,2} can't have brackets in it
+                while chars.next_if(|(_, c)| *c != '}').is_some() {}
+                chars.next();
+            },
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1).ok_or("unmatched close bracket")?,
+            ',' if depth == 0 => {
+                arguments.push(&text[start..index]);
+                start = index + 1;
+            },
+            _ => ()
+        }
+    }
+    if depth > 0 {
+        return Err("unmatched open bracket".to_string());
+    }
+    if !text[start..].trim().is_empty() || !arguments.is_empty() {
+        arguments.push(&text[start..]);
+    }
+    Ok(arguments)
+}
+
+/// Counts the passwords which follow the policy, with every one which doesn't (and why) in the
+/// details, and logged at -v
+pub fn solve(text: &str, policy: &dyn Policy) -> Result<Solution, Error> {
+    let checks: Vec<PasswordCheck> = input::parse_lines(text)?;
+    let mut failures = Vec::new();
+    for (index, check) in checks.iter().enumerate() {
+        if let Err(reason) = policy.check(check) {
+            debug!("Line {} ({}): {}", index + 1, check, reason);
+            failures.push(Json::object(vec![
+                ("line", (index + 1).into()),
+                ("entry", check.to_string().into()),
+                ("reason", reason.into())
+            ]));
+        }
+    }
+    info!("{} of {} passwords don't follow the policy", failures.len(), checks.len());
+    Ok(Solution::new(checks.len() - failures.len(), Json::object(vec![
+        ("failures", failures.into())
+    ])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, split_arguments, PasswordCheck};
+
+    fn check(spec: &str, line: &str) -> Result<(), String> {
+        let entry: PasswordCheck = line.parse().unwrap();
+        parse(spec).unwrap().check(&entry)
+    }
+
+    #[test]
+    fn splits_nested_arguments() {
+        assert_eq!(split_arguments("count, any(positions, regex:a{1,2}), min-length:3"), Ok(vec!["count", " any(positions, regex:a{1,2})", " min-length:3"]));
+        assert_eq!(split_arguments(r"regex:\), regex:[),(], regex:[]a,], regex:[^],]"), Ok(vec![r"regex:\)", " regex:[),(]", " regex:[]a,]", " regex:[^],]"]));
+        assert_eq!(split_arguments("regex:a]b, regex:a{1,2}}, count"), Ok(vec!["regex:a]b", " regex:a{1,2}}", " count"]));
+        assert_eq!(split_arguments(""), Ok(vec![]));
+        assert_eq!(split_arguments("any(count"), Err("unmatched open bracket".to_string()));
+        assert_eq!(split_arguments("regex:[a"), Err("unmatched open bracket".to_string()));
+        assert_eq!(split_arguments("count)"), Err("unmatched close bracket".to_string()));
+    }
+
+    #[test]
+    fn reads_each_policy() {
+        assert!(check(r"all(count, regex:\), regex:[)])", "1-3 a: a)bc").is_ok());
+        assert!(check("any(positions, min-length:8)", "1-3 a: abcdefgh").is_ok());
+        assert_eq!(check("all(count, positions)", "1-3 b: cdefg"), Err("'b' appears 0 times rather than 1-3, and 'b' is at neither position 1 nor 3".to_string()));
+        assert_eq!(check("classes:lud", "1-3 a: abc\u{663}"), Err("has no uppercase letter or digit".to_string()));
+        assert!(check("classes:d", "1-3 a: abc7").is_ok());
+        assert_eq!(check("classes:lus", "1-3 a: ÉTÉ"), Err("has no lowercase letter".to_string()));
+        assert!(check("regex:a]", "1-3 a: ba]").is_ok());
+    }
+
+    #[test]
+    fn explains_invalid_policies() {
+        let error = |spec: &str| parse(spec).err().unwrap();
+        assert_eq!(error("all()"), "Invalid policy 'all()': expected at least one policy to combine");
+        assert_eq!(error("any(count"), "Invalid policy 'any(count': expected a closing bracket");
+        assert_eq!(error("all(count))"), "Invalid policy 'all(count))': unmatched close bracket");
+        assert_eq!(error("classes:x"), "Invalid policy 'classes:x': expected classes from l, u, d and s");
+        assert_eq!(error("min-length:many"), "Invalid policy 'min-length:many': expected a number of characters");
+        assert_eq!(error("all(count, sometimes)"), "Invalid policy 'sometimes': expected count, positions, regex:, min-length:, classes:, all(...) or any(...)");
+    }
+}
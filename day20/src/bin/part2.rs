@@ -0,0 +1,23 @@
+use std::env;
+use common::input;
+use common::log;
+use common::output;
+use common::render;
+use common::run;
+use day20::part2_solution;
+
+const USAGE: &str = "Please provide arguments: Filename... [--render <file.png|ppm|svg>] [--timeout <seconds>] [--format text|json] [-q|-v|-vv]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    log::take_verbosity(&mut args);
+    let format = output::or_usage(output::take_format(&mut args), USAGE);
+    output::or_usage(render::take_flags(&mut args), USAGE);
+    output::or_usage(run::take_flags(&mut args), USAGE);
+    let (files, flags) = input::split_files(&args[1..]);
+    if !files.is_empty() && flags.is_empty() {
+        output::print_each(format, 20, Some(2), files, |_filename, text| part2_solution(text));
+    } else {
+        println!("{}", USAGE);
+    }
+}
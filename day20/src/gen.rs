@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+use common::gen::{Generated, Rng};
+use common::grid::Grid;
+use crate::part2::MONSTER_DATA;
+
+const TILE: usize = 10;
+const LAST: usize = TILE - 1;
+const INNER: usize = TILE - 2; // without borders
+
+/// A square of size tiles on each side (12 by default, from 3 to 12), cut from an image with sea
+/// monsters placed at random, where every edge is unique (even when flipped) and not the same both
+/// ways round, so that there is only one arrangement, and no monsters were made by chance. Each tile
+/// is then rotated and flipped at random, and the tiles are shuffled.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let side = size.unwrap_or(12).clamp(3, 12);
+    let monster: Grid<bool> = Grid::parse(MONSTER_DATA, "'#' or '.'", |c| Some(c == '#')).unwrap();
+    let (image, monsters) = sea_with_monsters(rng, side * INNER, &monster);
+    let (horizontal, vertical) = unique_edges(rng, side);
+    let mut ids: HashSet<usize> = HashSet::new();
+    let mut tiles: Vec<(usize, Grid<bool>)> = Vec::new();
+    let mut corners: usize = 1;
+    for row in 0..side {
+        for col in 0..side {
+            let tile = Grid::new(TILE, TILE, false).map(|(r, c), _| match (r, c) {
+                (0, _) => horizontal[row][col][c],
+                (LAST, _) => horizontal[row + 1][col][c],
+                (_, 0) => vertical[row][col][r],
+                (_, LAST) => vertical[row][col + 1][r],
+                _ => image[(row * INNER + r - 1, col * INNER + c - 1)]
+            });
+            let id = loop {
+                let id = rng.range(1000, 9999);
+                if ids.insert(id) {
+                    break id;
+                }
+            };
+            if (row == 0 || row == side - 1) && (col == 0 || col == side - 1) {
+                corners *= id;
+            }
+            let orientation = rng.below(8);
+            tiles.push((id, tile.orientations()[orientation].clone()));
+        }
+    }
+    rng.shuffle(&mut tiles);
+    let text: Vec<String> = tiles.iter().map(|(id, tile)| format!("Tile {}:\n{}", id, tile.render(|pixel| if *pixel { '#' } else { '.' }))).collect();
+    let roughness = image.count(|pixel| *pixel) - monsters * monster.count(|pixel| *pixel);
+    Generated::new(text.join("\n\n"))
+        .answer(1, corners)
+        .answer(2, roughness)
+}
+
+/// An image with about a third of the pixels set, and as many sea monsters as fit without
+/// overlapping in a few tries, along with the number of them
+fn sea_with_monsters(rng: &mut Rng, size: usize, monster: &Grid<bool>) -> (Grid<bool>, usize) {
+    loop {
+        let mut image = Grid::new(size, size, false).map(|_, _| rng.chance(1, 3));
+        let mut placed: Vec<(usize, usize)> = Vec::new();
+        for _ in 0..(size * size / 256) {
+            let row = rng.range(0, size - monster.height());
+            let col = rng.range(0, size - monster.width());
+            let overlaps = placed.iter().any(|(r, c)| row.abs_diff(*r) < monster.height() && col.abs_diff(*c) < monster.width());
+            if !overlaps {
+                placed.push((row, col));
+                for ((r, c), pixel) in monster.positions() {
+                    if *pixel {
+                        image[(row + r, col + c)] = true;
+                    }
+                }
+            }
+        }
+        // the placed monsters are always found, so any more must have been made by chance
+        let found: usize = monster.orientations().iter().map(|m| count_pattern(&image, m)).sum();
+        if found == placed.len() {
+            return (image, placed.len());
+        }
+    }
+}
+
+fn count_pattern(image: &Grid<bool>, pattern: &Grid<bool>) -> usize {
+    let mut count = 0;
+    for row in 0..=(image.height() - pattern.height()) {
+        for col in 0..=(image.width() - pattern.width()) {
+            if pattern.positions().all(|((r, c), pixel)| !*pixel || image[(row + r, col + c)]) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+type Edges = Vec<Vec<Vec<bool>>>;
+
+/// The borders between tiles, as horizontal[row][col] along the top of each tile (left to right) and
+/// vertical[row][col] down the left of each (top to bottom), including those along the bottom and
+/// right of the last row and column, where tiles which meet at a corner share its pixel
+fn unique_edges(rng: &mut Rng, side: usize) -> (Edges, Edges) {
+    'attempt: loop {
+        let corners: Vec<Vec<bool>> = (0..=side).map(|_| (0..=side).map(|_| rng.chance(1, 2)).collect()).collect();
+        let mut used: HashSet<Vec<bool>> = HashSet::new();
+        let mut edge = |rng: &mut Rng, start: bool, end: bool| -> Option<Vec<bool>> {
+            for _ in 0..100 {
+                let mut pixels = vec![start];
+                pixels.extend((0..INNER).map(|_| rng.chance(1, 2)));
+                pixels.push(end);
+                let reversed: Vec<bool> = pixels.iter().rev().cloned().collect();
+                if pixels != reversed && !used.contains(&pixels) && !used.contains(&reversed) {
+                    used.insert(pixels.clone());
+                    return Some(pixels);
+                }
+            }
+            None
+        };
+        let mut horizontal: Edges = Vec::new();
+        for row in 0..=side {
+            let mut edges = Vec::new();
+            for col in 0..side {
+                match edge(rng, corners[row][col], corners[row][col + 1]) {
+                    Some(pixels) => edges.push(pixels),
+                    None => continue 'attempt
+                }
+            }
+            horizontal.push(edges);
+        }
+        let mut vertical: Edges = Vec::new();
+        for row in 0..side {
+            let mut edges = Vec::new();
+            for col in 0..=side {
+                match edge(rng, corners[row][col], corners[row + 1][col]) {
+                    Some(pixels) => edges.push(pixels),
+                    None => continue 'attempt
+                }
+            }
+            vertical.push(edges);
+        }
+        return (horizontal, vertical);
+    }
+}
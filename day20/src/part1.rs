@@ -0,0 +1,146 @@
+use std::str::FromStr;
+use regex::Regex;
+use lazy_static::lazy_static;
+use std::fmt;
+use common::input;
+use common::answer::Solution;
+use common::error::{self, ParseError};
+use common::json::Json;
+use common::{debug, info};
+
+const SIZE: usize = 10;
+
+struct Tile {
+    number: usize,
+    data: [[bool; SIZE]; SIZE],
+    // cached for frequent access:
+    top: [bool; SIZE],
+    bottom: [bool; SIZE],
+    left: [bool; SIZE],
+    right: [bool; SIZE],
+}
+
+impl FromStr for Tile {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref TILE_NUMBER: Regex = Regex::new("^Tile (\\d+):$").unwrap();
+        }
+        let lines: Vec<&str> = input::lines(text).collect();
+        let header = lines.first().cloned().unwrap_or("");
+        let number: usize = match TILE_NUMBER.captures(header) {
+            Some(number_match) => error::parse_part(header, number_match.get(1).unwrap().as_str(), "a tile number")?,
+            None => return Err(ParseError::new(header, "Expected 'Tile <number>:'".to_string()).on_line(1))
+        };
+        if lines.len() != SIZE+1 {
+            return Err(ParseError::new(lines[lines.len()-1], format!("Expected {} rows but found {}", SIZE, lines.len()-1)).on_line(lines.len()));
+        }
+        let mut data = [[false; SIZE]; SIZE];
+        for row in 0..SIZE {
+            let line = lines[row+1];
+            if line.chars().count() != SIZE {
+                return Err(ParseError::new(line, format!("Row should be {} chars long", SIZE)).on_line(row+2));
+            }
+            for (col, c) in line.chars().enumerate() {
+                data[row][col] = match c {
+                    '#' => true,
+                    '.' => false,
+                    _ => return Err(ParseError::new(line, format!("Expected '#' or '.' but found '{}'", c)).at_column(col+1).on_line(row+2))
+                }
+            }
+        }
+        Ok(Tile::new(number, data))
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Tile {}:", self.number)?;
+        for row in 0..SIZE {
+            let mut s = String::new();
+            for col in 0..SIZE {
+                if self.data[row][col] {
+                    s.push('#');
+                } else {
+                    s.push('.');
+                }
+            }
+            writeln!(f, "{}", s)?;
+        }
+        Ok(())
+    }
+}
+
+impl Tile {
+    fn edges(&self) -> [&[bool; SIZE]; 4] {
+        [&self.top, &self.bottom, &self.left, &self.right]
+    }
+
+    fn new(number: usize, data: [[bool; SIZE]; SIZE]) -> Self {
+        let top: [bool; SIZE] = data[0];
+        let bottom: [bool; SIZE] = data[SIZE-1];
+        let mut left: [bool; SIZE] = [false; SIZE];
+        let mut right: [bool; SIZE] = [false; SIZE];
+        for row in 0..SIZE {
+            left[row] = data[row][0];
+            right[row] = data[row][SIZE-1];
+        }
+        Tile {
+            number,
+            data,
+            top,
+            bottom,
+            left,
+            right,
+        }
+    }
+}
+
+pub fn solve(text: &str) -> Result<Solution, ParseError> {
+    let tiles: Vec<Tile> = input::parse_blocks(text)?;
+    
+    let mut corners: Vec<&Tile> = Vec::new();
+    for tile in tiles.iter() {
+        let mut matching_edges: usize = 0;
+        for edge in tile.edges().iter() {
+            let other_matching_edges = tiles.iter().filter(|t| t.number != tile.number).map(|t| t.edges().iter().filter(|e| could_match(e,edge)).count()).sum();
+            match other_matching_edges {
+                0 => (),
+                1 => matching_edges += 1,
+                _ => info!("PROBLEM: Tile {} edge could match {} other edges", tile.number, other_matching_edges)
+            }
+        }
+        debug!("Tile {} matches on {} edges", tile.number, matching_edges);
+        if matching_edges == 2 {
+            corners.push(tile);
+        }
+    }
+
+    let corners: Vec<usize> = corners.iter().map(|t| t.number).collect();
+    info!("Found {} corners: {:?}", corners.len(), corners);
+    Ok(Solution::new(corners.iter().product::<usize>(), Json::object(vec![
+        ("corners", corners.into())
+    ])))
+}
+
+fn could_match(a: &[bool; SIZE], b: &[bool; SIZE])-> bool {
+    itertools::equal(a, b) || itertools::equal(a.iter().rev(), b)
+}
+#[cfg(test)]
+mod tests {
+    use common::gen::Rng;
+    use super::{Tile, SIZE};
+
+    #[test]
+    fn display_round_trips() {
+        let mut rng = Rng::new(20);
+        for _ in 0..100 {
+            let rows: Vec<String> = (0..SIZE).map(|_| (0..SIZE).map(|_| *rng.pick(&['#', '.'])).collect()).collect();
+            let text = format!("Tile {}:\n{}", rng.range(1, 9999), rows.join("\n"));
+            let shown = text.parse::<Tile>().unwrap().to_string();
+            assert_eq!(shown.trim_end(), text);
+            assert_eq!(shown.parse::<Tile>().unwrap().to_string(), shown);
+        }
+    }
+}
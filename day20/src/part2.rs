@@ -1,15 +1,17 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 use std::str::FromStr;
 use regex::Regex;
 use lazy_static::lazy_static;
 use std::fmt;
+use common::input;
+use common::answer::Solution;
+use common::error::{self, Error, ParseError};
+use common::grid::Grid;
+use common::json::Json;
+use common::render::{self, Picture, Rgb};
+use common::{info, trace};
 
-const NEW_LINE: &str = "\r\n";
-const DOUBLE_NEW_LINE: &str = "\r\n\r\n";
-
-const MONSTER_DATA: &str = "                  # \r\n#    ##    ##    ###\r\n #  #  #  #  #  #   ";
+pub(crate) const MONSTER_DATA: &str = "..................#.\n#....##....##....###\n.#..#..#..#..#..#...";
 
 struct EdgeMap {
     edges: HashMap<Vec<bool>, Vec<Edge>>
@@ -89,74 +91,58 @@ struct Tile {
 }
 
 impl FromStr for Tile {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
         lazy_static! {
             static ref TILE_NUMBER: Regex = Regex::new("^Tile (\\d+):$").unwrap();
         }
-        let mut lines: Vec<&str> = text.split(NEW_LINE).collect();
-        let number: usize = match TILE_NUMBER.captures(lines.remove(0)) {
-            Some(number_match) => number_match.get(1).unwrap().as_str().parse().expect("This regex should only return a number"),
-            None => return Err("Tile number not found".to_string())
+        let header = input::lines(text).next().unwrap_or("");
+        let number: usize = match TILE_NUMBER.captures(header) {
+            Some(number_match) => error::parse_part(header, number_match.get(1).unwrap().as_str(), "a tile number")?,
+            None => return Err(ParseError::new(header, "Expected 'Tile <number>:'".to_string()).on_line(1))
+        };
+        let body = match text.find('\n') {
+            Some(index) => &text[index+1..],
+            None => return Err(ParseError::new(header, "Expected the tile image on the following lines".to_string()).on_line(1))
         };
-        Ok(Tile { number, image: lines.join(NEW_LINE).parse()? })
+        Ok(Tile { number, image: body.parse().map_err(|e: ParseError| e.on_line(2))? })
     }
 }
 
 impl FromStr for Image {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let lines = text.split(NEW_LINE);
-        let mut data = Vec::new();
-        for line in lines {
-            let mut row = Vec::new();
-            for c in line.chars() {
-                row.push(match c {
-                    '#' => true,
-                    _ => false,
-                });
-            }
-            data.push(row);
-        }
-        Ok(Image { data })
+        Ok(Image {
+            data: Grid::parse(text, "'#' or '.'", |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None
+            })?
+        })
     }
 }
 
 impl fmt::Display for Tile {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "Tile {}:\r\n{}", self.number, self.image)
+        writeln!(f, "Tile {}:\n{}", self.number, self.image)
     }
 }
 
 impl fmt::Display for Image {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for row in self.data.iter() {
-            let mut s = String::new();
-            for value in row {
-                if *value {
-                    s.push('#');
-                } else {
-                    s.push('.');
-                }
-            }
-            writeln!(f, "{}", s)?;
-        }
-        Ok(())
+        write!(f, "{}", self.data.render(|value| if *value { '#' } else { '.' }))
     }
 }
 
 impl Tile {
     fn get_edges(&self) -> Vec<Edge> {
-        let top = self.image.data[0].clone();
-        let bottom = self.image.data[self.image.data.len()-1].clone();
-        let mut left = Vec::new();
-        let mut right = Vec::new();
-        for row in self.image.data.iter() {
-            left.push(row[0]);
-            right.push(row[row.len()-1]);
-        }
+        let data = &self.image.data;
+        let top = data.row(0).to_vec();
+        let bottom = data.row(data.height()-1).to_vec();
+        let left = data.column(0).cloned().collect();
+        let right = data.column(data.width()-1).cloned().collect();
         vec![
             Edge { tile: self.number, location: EdgeLocation::Top, data: top},
             Edge { tile: self.number, location: EdgeLocation::Bottom, data: bottom},
@@ -197,13 +183,14 @@ impl Tile {
         unmatched
     }
 
-    fn without_borders(&self) -> Vec<Vec<bool>> {
-        self.image.data[1..(self.image.data.len()-1)].iter().map(|row| row[1..(row.len()-1)].to_vec()).collect()
+    fn without_borders(&self) -> Grid<bool> {
+        let data = &self.image.data;
+        data.sub_grid((1, 1), data.width()-2, data.height()-2)
     }
 }
 
 struct Image {
-    data: Vec<Vec<bool>>
+    data: Grid<bool>
 }
 
 #[derive(Hash, PartialEq, Eq)]
@@ -217,8 +204,11 @@ impl Image {
         let mut found = Vec::new();
         let image_size = self.bounds();
         let pattern_size = pattern.bounds();
-        for row in 0..(image_size.row - pattern_size.row) {
-            for col in 0..(image_size.col - pattern_size.col) {
+        if pattern_size.row > image_size.row || pattern_size.col > image_size.col {
+            return found;
+        }
+        for row in 0..=(image_size.row - pattern_size.row) {
+            for col in 0..=(image_size.col - pattern_size.col) {
                 let starting_point = Point { row, col };
                 if self.verify(pattern, &starting_point) {
                     found.push(starting_point);
@@ -230,86 +220,59 @@ impl Image {
 
     fn bounds(&self) -> Point {
         Point {
-            row: self.data.len(),
-            col: self.data[0].len()
+            row: self.data.height(),
+            col: self.data.width()
         }
     }
 
     fn verify(&self, pattern: &Image, offset: &Point) -> bool {
-        for (r, row) in pattern.data.iter().enumerate() {
-            for (c, value) in row.iter().enumerate() {
-                if *value && !self.data[offset.row+r][offset.col+c] {
-                    return false;
-                }
-            }
-        }
-        true
+        pattern.data.positions().all(|((r, c), value)| !*value || self.data[(offset.row+r, offset.col+c)])
     }
 
     fn count_active_pixels(&self) -> usize {
-        let mut count: usize = 0;
-        for row in self.data.iter() {
-            for value in row.iter() {
-                if *value {
-                    count += 1;
-                }
-            }
-        }
-        count
+        self.data.count(|value| *value)
     }
 
     fn from_placed_tiles(tiles: &Vec<Vec<Tile>>) -> Self {
         let mut image: Vec<Vec<bool>> = Vec::new();
         for tile_row in tiles.iter() {
-            let mut new_image_rows: Vec<Vec<bool>> = tile_row[0].without_borders();
-            for tile in tile_row.iter().skip(1) {
-                for (data_row_index, data_row) in tile.without_borders().iter().enumerate() {
-                    for data_point in data_row {
-                        new_image_rows[data_row_index].push(*data_point);
-                    }
-                }
+            let borderless: Vec<Grid<bool>> = tile_row.iter().map(|tile| tile.without_borders()).collect();
+            for data_row_index in 0..borderless[0].height() {
+                image.push(borderless.iter().flat_map(|data| data.row(data_row_index).iter().cloned()).collect());
             }
-            image.append(&mut new_image_rows);
         }
-        Image { data: image }
+        Image { data: Grid::from_rows(image).unwrap() } // every tile is the same size
     }
 
     fn flip_vertical(&self) -> Image {
-        let mut flipped_data = Vec::new();
-        for row in self.data.iter() {
-            flipped_data.insert(0, row.clone())
-        }
-        Image { data: flipped_data }
+        Image { data: self.data.flip_vertical() }
     }
 
     fn rotate_clockwise(&self) -> Image {
-        let transposed = transpose(&self.data);
-        let mut new_data: Vec<Vec<bool>> = Vec::new();
-        for row in transposed.iter() {
-            new_data.push(row.iter().rev().map(|b| *b).collect());
-        }
-        Image { data: new_data }
+        Image { data: self.data.rotate_clockwise() }
     }
 
     fn all_orientations(&self) -> [Image; 8] {
-        let original = Image { data: self.data.clone() };
-        let flipped = original.flip_vertical();
-        let original90 = original.rotate_clockwise();
-        let flipped90 = flipped.rotate_clockwise();
-        let original180 = original90.rotate_clockwise();
-        let flipped180 = flipped90.rotate_clockwise();
-        let original270 = original180.rotate_clockwise();
-        let flipped270 = flipped180.rotate_clockwise();
-        [
-            original, flipped,
-            original90, flipped90,
-            original180, flipped180,
-            original270, flipped270
-        ]
+        self.data.orientations().map(|data| Image { data })
+    }
+
+    /// The sea, with the pixels of any of the patterns which are found in it highlighted
+    fn to_picture(&self, patterns: &[Image]) -> Picture {
+        let mut colours = self.data.map(|_position, wave| if *wave { Rgb(120, 180, 230) } else { Rgb(20, 60, 120) });
+        for pattern in patterns.iter() {
+            for offset in self.find_pattern(pattern) {
+                for ((r, c), value) in pattern.data.positions() {
+                    if *value {
+                        colours[(offset.row + r, offset.col + c)] = Rgb(240, 120, 30);
+                    }
+                }
+            }
+        }
+        Picture::new(colours)
     }
 }
 
-fn arrange_tiles(tiles: &Vec<Tile>, edges: &EdgeMap) -> Vec<Vec<Tile>> {
+fn arrange_tiles(tiles: &Vec<Tile>, edges: &EdgeMap) -> Result<Vec<Vec<Tile>>, String> {
     let grid = (tiles.len() as f64).sqrt() as usize;
     let mut placed: HashMap<Point,Tile> = HashMap::new();
     for row in 0..grid {
@@ -318,30 +281,35 @@ fn arrange_tiles(tiles: &Vec<Tile>, edges: &EdgeMap) -> Vec<Vec<Tile>> {
             let edge_top = find_matching_edge(&placed, &edges, row as isize - 1, col as isize, &EdgeLocation::Bottom);
             let this_tile: Tile = match (edge_left, edge_top) {
                 (Some(left), Some(top)) => { // not first row or column
-                    assert_eq!(left.tile, top.tile);
+                    if left.tile != top.tile {
+                        return Err(format!("Tiles {} and {} both fit at row {} column {}", left.tile, top.tile, row, col));
+                    }
                     let existing_tile = find_tile(&tiles, left.tile);
-                    existing_tile.transform(left.location, top.location).unwrap()
+                    existing_tile.transform(left.location, top.location)?
                 },
                 (Some(left), None) => { // first row, not first column
                     let existing_tile = find_tile(&tiles, left.tile);
                     let unmatched_edge = existing_tile.find_unmatched_edge_locations(&edges)
-                        .into_iter().filter(|l| l.opposite() != left.location).nth(0).unwrap(); // matters for top right corner
-                    existing_tile.transform(left.location, unmatched_edge).unwrap()
+                        .into_iter().filter(|l| l.opposite() != left.location).nth(0) // matters for top right corner
+                        .ok_or(format!("Tile {} has no edge which could be at the top", existing_tile.number))?;
+                    existing_tile.transform(left.location, unmatched_edge)?
                 },
                 (None, Some(top)) => { // first column, not first row
                     let existing_tile = find_tile(&tiles, top.tile);
                     let unmatched_edge = existing_tile.find_unmatched_edge_locations(&edges)
-                        .into_iter().filter(|l| l.opposite() != top.location).nth(0).unwrap(); // matters for bottom left
-                    existing_tile.transform(unmatched_edge, top.location).unwrap()
+                        .into_iter().filter(|l| l.opposite() != top.location).nth(0) // matters for bottom left
+                        .ok_or(format!("Tile {} has no edge which could be on the left", existing_tile.number))?;
+                    existing_tile.transform(unmatched_edge, top.location)?
                     
                 },
-                (None, None) => { // only at (0,0)
+                (None, None) if row == 0 && col == 0 => {
                     let (existing_tile, unmatched_edges) = tiles.iter()
                         .map(|t| (t, t.find_unmatched_edge_locations(&edges))) // calc unmatched edges of tile
                         .filter(|(_t, u)| u.len() == 2) // find corners
-                        .nth(0).unwrap(); // pick any corner to start
-                    existing_tile.transform(unmatched_edges[1], unmatched_edges[0]).unwrap() // 2 possible orientations here, but doesn't matter, chosen only to match example for debugging
-                }
+                        .nth(0).ok_or("No tile is a corner")?; // pick any corner to start
+                    existing_tile.transform(unmatched_edges[1], unmatched_edges[0])? // 2 possible orientations here, but doesn't matter, chosen only to match example for debugging
+                },
+                (None, None) => return Err(format!("No tile fits at row {} column {}", row, col))
             };
             placed.insert(Point { row, col }, this_tile);
         }
@@ -355,7 +323,24 @@ fn arrange_tiles(tiles: &Vec<Tile>, edges: &EdgeMap) -> Vec<Vec<Tile>> {
         }
         data_vec.push(row_vec);
     }
-    data_vec
+    Ok(data_vec)
+}
+
+/// Tiles can only be arranged into a square image if there is a square number of them, and each is
+/// a square of the same size, with a border around at least one pixel
+fn check_tiles(tiles: &Vec<Tile>) -> Result<(), String> {
+    let grid = (tiles.len() as f64).sqrt() as usize;
+    if tiles.is_empty() || grid * grid != tiles.len() {
+        return Err(format!("Expected a square number of tiles but found {}", tiles.len()));
+    }
+    let size = tiles[0].image.data.height();
+    for tile in tiles.iter() {
+        let data = &tile.image.data;
+        if data.width() != size || data.height() != size || size < 3 {
+            return Err(format!("Tile {} is {}x{} but expected every tile to be the same square of at least 3x3", tile.number, data.width(), data.height()));
+        }
+    }
+    Ok(())
 }
 
 fn find_tile(tiles: &Vec<Tile>, number: usize) -> &Tile {
@@ -387,52 +372,72 @@ fn get_corners(placed_tiles: &Vec<Vec<Tile>>) -> [&Tile; 4] {
     ]
 }
 
-//source: https://stackoverflow.com/questions/64498617/how-to-transpose-a-vector-of-vectors-in-rust
-fn transpose<T>(v: &Vec<Vec<T>>) -> Vec<Vec<T>>
-where
-    T: Clone,
-{
-    assert!(!v.is_empty());
-    (0..v[0].len())
-        .map(|i| v.iter().map(|inner| inner[i].clone()).collect::<Vec<T>>())
-        .collect()
+pub fn solve(text: &str) -> Result<Solution, Error> {
+    let tiles: Vec<Tile> = input::parse_blocks(text)?;
+    check_tiles(&tiles)?;
+    let edges = EdgeMap::from_tiles(&tiles);
+    let placed = arrange_tiles(&tiles, &edges)?;
+    // confirm tiles are placed correctly
+    for row in placed.iter() {
+        trace!("{}", row.iter().map(|t| t.number.to_string()).collect::<Vec<String>>().join(" "));
+    }
+    // confirm result from part1
+    let corners: Vec<usize> = get_corners(&placed).iter().map(|t| t.number).collect();
+    info!("Found {} corners: {:?}", corners.len(), corners);
+    info!("Product: {}", corners.iter().product::<usize>());
+    // find result for part2
+    let image = Image::from_placed_tiles(&placed);
+    let monster: Image = MONSTER_DATA.parse().unwrap();
+    let monsters = monster.all_orientations();
+    let found_monsters: Vec<Point> = monsters.iter().flat_map(|m| image.find_pattern(m)).collect();
+    let image_pixels: usize = image.count_active_pixels();
+    let monster_pixels: usize = found_monsters.len() * monster.count_active_pixels();
+    let remaining_pixels = image_pixels.checked_sub(monster_pixels).ok_or("Sea monsters overlap")?;
+    info!("Found {} monsters", found_monsters.len());
+    render::save(|| image.to_picture(&monsters))?;
+    let positions: Vec<Json> = found_monsters.iter().map(|p| Json::object(vec![
+        ("row", p.row.into()),
+        ("col", p.col.into())
+    ])).collect();
+    Ok(Solution::new(remaining_pixels, Json::object(vec![
+        ("corners", corners.into()),
+        ("monsters", positions.into()),
+        ("image_pixels", image_pixels.into()),
+        ("monster_pixels", monster_pixels.into())
+    ])))
 }
 
+#[cfg(test)]
+mod tests {
+    use common::gen::Rng;
+    use super::{Image, Tile};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let tiles: Vec<Tile> = text.split(DOUBLE_NEW_LINE).map(|s| s.parse()
-            .expect(&format!("Error parsing tile {}", s))).collect();
-        let edges = EdgeMap::from_tiles(&tiles);
-        let placed = arrange_tiles(&tiles, &edges);
-        // confirm tiles are placed correctly
-        for row in placed.iter() {
-            for tile in row {
-                print!("{} ", tile.number);
-            }
-            println!("");
+    fn random_rows(rng: &mut Rng, width: usize, height: usize) -> String {
+        let rows: Vec<String> = (0..height).map(|_| (0..width).map(|_| *rng.pick(&['#', '.'])).collect()).collect();
+        rows.join("\n")
+    }
+
+    #[test]
+    fn tile_display_round_trips() {
+        let mut rng = Rng::new(20);
+        for _ in 0..100 {
+            let size = rng.range(3, 12);
+            let text = format!("Tile {}:\n{}", rng.range(1, 9999), random_rows(&mut rng, size, size));
+            let shown = text.parse::<Tile>().unwrap().to_string();
+            assert_eq!(shown.trim_end(), text);
+            assert_eq!(shown.parse::<Tile>().unwrap().to_string(), shown);
         }
-        println!("");
-        // confirm result from part1
-        let corners: Vec<usize> = get_corners(&placed).iter().map(|t| t.number).collect();
-        println!("Found {} corners: {:?}", corners.len(), corners);
-        println!("Product: {}", corners.iter().product::<usize>());
-        println!("");
-        // find result for part2
-        let image = Image::from_placed_tiles(&placed);
-        let monster: Image = MONSTER_DATA.parse().unwrap();
-        let monsters = monster.all_orientations();
-        let found_monsters: Vec<Point> = monsters.iter().flat_map(|m| image.find_pattern(m)).collect();
-        let image_pixels: usize = image.count_active_pixels();
-        let monster_pixels: usize = found_monsters.len() * monster.count_active_pixels();
-        let remaining_pixels = image_pixels - monster_pixels;
-        println!("Found {} monsters", found_monsters.len());
-        println!("Part 2 result: {}", remaining_pixels);
-    } else {
-        println!("Please provide 1 argument: Filename");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn image_display_round_trips() {
+        let mut rng = Rng::new(20);
+        for _ in 0..100 {
+            let (width, height) = (rng.range(1, 30), rng.range(1, 30));
+            let text = random_rows(&mut rng, width, height);
+            let shown = text.parse::<Image>().unwrap().to_string();
+            assert_eq!(shown.trim_end(), text);
+            assert_eq!(shown.parse::<Image>().unwrap().to_string(), shown);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+use common::gen::{Generated, Rng};
+
+const ALLERGENS: [&str; 9] = ["dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "shellfish", "soy", "wheat"];
+
+/// Labels for size foods (40 by default, or more to fit two for each allergen), with 3 to 8 allergens
+/// each in a different ingredient. Each allergen is listed on two foods which have no other
+/// ingredient in common, so that its ingredient is the only candidate, while the other foods have a
+/// mix of ingredients and list some of the allergens in them.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let mut allergens: Vec<&str> = ALLERGENS.to_vec();
+    rng.shuffle(&mut allergens);
+    allergens.truncate(rng.range(3, 8));
+    let size = size.unwrap_or(40).max(allergens.len() * 2);
+    let mut words: HashSet<String> = HashSet::new();
+    while words.len() < 200 + allergens.len() {
+        words.insert(rng.word(4, 8));
+    }
+    let mut words: Vec<String> = words.into_iter().collect();
+    words.sort(); // since a HashSet's order isn't the same for the same seed
+    rng.shuffle(&mut words);
+    let (dangerous, safe) = words.split_at(allergens.len()); // dangerous[i] contains allergens[i]
+    let mut foods: Vec<(Vec<&str>, Vec<&str>)> = Vec::new(); // ingredients, listed allergens
+    for (i, allergen) in allergens.iter().enumerate() {
+        let mut others: Vec<&str> = safe.iter().map(|s| s.as_str()).collect();
+        rng.shuffle(&mut others);
+        let split = rng.range(3, 15);
+        for list in [&others[..split], &others[split..(split * 2)]].iter() {
+            let mut ingredients = list.to_vec();
+            ingredients.push(&dangerous[i]);
+            foods.push((ingredients, vec![allergen]));
+        }
+    }
+    while foods.len() < size {
+        let mut ingredients: Vec<&str> = (0..rng.range(3, 20)).map(|_| rng.pick(safe).as_str()).collect();
+        let mut listed = Vec::new();
+        for (i, allergen) in allergens.iter().enumerate() {
+            if rng.chance(1, 3) {
+                ingredients.push(&dangerous[i]);
+                if rng.chance(1, 2) {
+                    listed.push(*allergen);
+                }
+            }
+        }
+        if listed.is_empty() {
+            let i = rng.below(allergens.len());
+            ingredients.push(&dangerous[i]);
+            listed.push(allergens[i]);
+        }
+        let mut unique: Vec<&str> = Vec::new();
+        for ingredient in ingredients {
+            if !unique.contains(&ingredient) {
+                unique.push(ingredient);
+            }
+        }
+        foods.push((unique, listed));
+    }
+    rng.shuffle(&mut foods);
+    let mut lines = Vec::new();
+    let mut safe_count = 0;
+    for (ingredients, listed) in foods.iter_mut() {
+        rng.shuffle(ingredients);
+        rng.shuffle(listed);
+        safe_count += ingredients.iter().filter(|i| !dangerous.iter().any(|d| d == *i)).count();
+        lines.push(format!("{} (contains {})", ingredients.join(" "), listed.join(", ")));
+    }
+    let mut pairs: Vec<(&str, &str)> = allergens.iter().cloned().zip(dangerous.iter().map(|d| d.as_str())).collect();
+    pairs.sort();
+    let canonical: Vec<&str> = pairs.iter().map(|(_, ingredient)| *ingredient).collect();
+    Generated::new(lines.join("\n"))
+        .answer(1, safe_count)
+        .answer(2, canonical.join(","))
+}
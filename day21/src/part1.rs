@@ -1,12 +1,13 @@
 use std::collections::HashSet;
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 use std::str::FromStr;
 use regex::Regex;
 use lazy_static::lazy_static;
-
-const NEW_LINE: &str = "\r\n";
+use common::input;
+use common::answer::Solution;
+use common::error::{Error, ParseError};
+use common::json::Json;
+use common::debug;
 
 #[derive(Clone, Hash, Eq, PartialEq, Debug)]
 struct Allergen(String);
@@ -20,7 +21,7 @@ struct FoodLabel {
 }
 
 impl FromStr for FoodLabel {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
         lazy_static! {
@@ -32,7 +33,7 @@ impl FromStr for FoodLabel {
                 let allergens: HashSet<Allergen> = line_match.get(2).unwrap().as_str().split(", ").map(|s| Allergen(s.to_string())).collect();
                 Ok(FoodLabel { ingredients, allergens })
             },
-            None => Err(format!("Line did not match regex: {}", line))
+            None => Err(ParseError::new(line, "Expected '<ingredients> (contains <allergens>)'".to_string()))
         }
     }
 }
@@ -75,26 +76,27 @@ impl AllergenMap {
             ingredients_without_allergens: remaining_ingredients
         })
     }
+
+    // eg: {"allergens": {"dairy": "mxmxvkd", "fish": "sqjhc"}, "safe_ingredients": ["kfcds", "nhms"]}
+    fn to_json(&self) -> Json {
+        let mut pairs: Vec<(&Allergen, &Ingredient)> = self.ingredients_with_allergens.iter().collect();
+        pairs.sort_by(|(a, _), (b, _)| a.0.cmp(&b.0));
+        let mut safe: Vec<String> = self.ingredients_without_allergens.iter().map(|i| i.0.to_string()).collect();
+        safe.sort();
+        Json::object(vec![
+            ("allergens", Json::Object(pairs.into_iter().map(|(a, i)| (a.0.to_string(), i.0.as_str().into())).collect())),
+            ("safe_ingredients", safe.into())
+        ])
+    }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let labels: Vec<FoodLabel> = text.split(NEW_LINE).map(|s| s.parse()
-            .expect(&format!("Error parsing FoodLabel: {}", s))).collect();
-        let map = AllergenMap::from_labels(&labels).expect("Error matching allergens");
-        for (allergen, ingredient) in map.ingredients_with_allergens.iter() {
-            println!("{:?} contains {:?}", ingredient, allergen);
-        }
-        println!("");
-        println!("Ingredients without allergens: {:?}", map.ingredients_without_allergens);
-        println!("");
-        let count: usize = map.ingredients_without_allergens.iter().map(|i| labels.iter().filter(|l| l.ingredients.contains(i)).count()).sum();
-        println!("Number of times these appear on labels: {}", count);
-    } else {
-        println!("Please provide 1 argument: Filename");
+pub fn solve(text: &str) -> Result<Solution, Error> {
+    let labels: Vec<FoodLabel> = input::parse_lines(text)?;
+    let map = AllergenMap::from_labels(&labels)?;
+    for (allergen, ingredient) in map.ingredients_with_allergens.iter() {
+        debug!("{:?} contains {:?}", ingredient, allergen);
     }
-}
\ No newline at end of file
+    debug!("Ingredients without allergens: {:?}", map.ingredients_without_allergens);
+    let count: usize = map.ingredients_without_allergens.iter().map(|i| labels.iter().filter(|l| l.ingredients.contains(i)).count()).sum();
+    Ok(Solution::new(count, map.to_json()))
+}
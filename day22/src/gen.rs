@@ -0,0 +1,11 @@
+use common::gen::{Generated, Rng};
+
+/// A shuffled deck of twice size cards (25 by default), dealt to both players, with no known answers,
+/// since the only way to find out who wins is to play the game
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(25).max(1);
+    let mut cards: Vec<usize> = (1..=(size * 2)).collect();
+    rng.shuffle(&mut cards);
+    let deck = |cards: &[usize]| cards.iter().map(|c| c.to_string()).collect::<Vec<String>>().join("\n");
+    Generated::new(format!("Player 1:\n{}\n\nPlayer 2:\n{}", deck(&cards[..size]), deck(&cards[size..])))
+}
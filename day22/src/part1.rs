@@ -0,0 +1,158 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::fmt;
+use common::input;
+use common::answer::Solution;
+use common::error::{self, Error, ParseError};
+use common::json::Json;
+use common::number::Number;
+use common::info;
+use common::run::Task;
+
+struct Player {
+    name: String,
+    cards: VecDeque<usize>
+}
+
+impl FromStr for Player {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut lines = input::lines(text);
+        let name: String = match lines.next() {
+            Some(header) => {
+                if header.ends_with(":") {
+                    header[0..header.len()-1].to_string()
+                } else {
+                    return Err(ParseError::new(header, "Expected '<player name>:'".to_string()).on_line(1))
+                }
+            },
+            None => return Err(ParseError::new("", "Header is missing".to_string()).on_line(1))
+        };
+        let mut cards: VecDeque<usize> = VecDeque::new();
+        for (index, s) in lines.enumerate() {
+            cards.push_back(error::parse_part(s, s, "a card").map_err(|e| e.on_line(index+2))?);
+        }
+        Ok(Player {
+            name,
+            cards
+        })
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:", self.name)?;
+        for card in self.cards.iter() {
+            write!(f, "\n{}", card)?;
+        }
+        Ok(())
+    }
+}
+
+impl Player {
+    fn has_no_cards(&self) -> bool {
+        self.cards.len() == 0
+    }
+
+    fn calculate_score(&self) -> Number {
+        let mut score = Number::zero();
+        let mut value: usize = self.cards.len();
+        for card in self.cards.iter() {
+            score += Number::from(*card) * value;
+            value -= 1;
+        }
+        score
+    }
+}
+
+struct Combat {
+    round: usize,
+    player1: Player,
+    player2: Player
+}
+
+impl Combat {
+    fn play_round(&mut self) -> &Player {
+        self.round += 1;
+        let card1 = self.player1.cards.pop_front().expect("Cannot play if player1 has no cards");
+        let card2 = self.player2.cards.pop_front().expect("Cannot play if player2 has no cards");
+        assert_ne!(card1, card2);
+        if card1 > card2 {
+            self.player1.cards.push_back(card1);
+            self.player1.cards.push_back(card2);
+            &self.player1
+        } else {
+            self.player2.cards.push_back(card2);
+            self.player2.cards.push_back(card1);
+            &self.player2
+        }
+    }
+
+    fn has_winner(&self) -> Option<&Player> {
+        if self.player1.has_no_cards() {
+            Some(&self.player2)
+        } else if self.player2.has_no_cards() {
+            Some(&self.player1)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn solve(text: &str) -> Result<Solution, Error> {
+    let mut players: VecDeque<Player> = input::parse_blocks(text)?.into_iter().collect();
+    if players.len() != 2 {
+        return Err(ParseError::new("", format!("Expected 2 players but found {}", players.len())).into());
+    }
+    let mut game = Combat {
+        round: 0,
+        player1: players.pop_front().unwrap(),
+        player2: players.pop_front().unwrap()
+    };
+    check_cards(&game.player1, &game.player2)?;
+    let mut task = Task::new("playing combat");
+    loop {
+        if let Some(winner) = game.has_winner() {
+            info!("{} wins after {} rounds!", winner.name, game.round);
+            info!("{}", winner);
+            return Ok(Solution::new(winner.calculate_score(), Json::object(vec![
+                ("winner", winner.name.as_str().into()),
+                ("rounds", game.round.into()),
+                ("deck", winner.cards.iter().cloned().collect::<Vec<usize>>().into())
+            ])));
+        }
+        task.tick()?;
+        game.play_round();
+    }
+}
+
+/// Every card must be different, as cards change hands and two the same would make a round with no winner
+fn check_cards(player1: &Player, player2: &Player) -> Result<(), Error> {
+    let mut cards: Vec<&usize> = player1.cards.iter().chain(player2.cards.iter()).collect();
+    cards.sort();
+    match cards.windows(2).find(|pair| pair[0] == pair[1]) {
+        Some(pair) => Err(Error::Solve(format!("There is more than one card {}", pair[0]))),
+        None => Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::gen::Rng;
+    use super::Player;
+
+    #[test]
+    fn display_round_trips() {
+        let mut rng = Rng::new(22);
+        for _ in 0..100 {
+            let mut cards: Vec<usize> = (1..=rng.range(1, 50)).collect();
+            rng.shuffle(&mut cards);
+            cards.truncate(rng.range(0, cards.len()));
+            let text = format!("Player {}:{}", rng.range(1, 2), cards.iter().map(|card| format!("\n{}", card)).collect::<String>());
+            let shown = text.parse::<Player>().unwrap().to_string();
+            assert_eq!(shown, text);
+            assert_eq!(shown.parse::<Player>().unwrap().to_string(), shown);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::fmt;
+use common::input;
+use common::answer::Solution;
+use common::checkpoint::{Checkpoint, Checkpointer};
+use common::error::{self, Error, ParseError};
+use common::json::Json;
+use common::number::Number;
+use common::{debug, info};
+use common::run::Task;
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+struct Player {
+    name: String,
+    cards: VecDeque<usize>
+}
+
+impl FromStr for Player {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut lines = input::lines(text);
+        let name: String = match lines.next() {
+            Some(header) => {
+                if header.ends_with(":") {
+                    header[0..header.len()-1].to_string()
+                } else {
+                    return Err(ParseError::new(header, "Expected '<player name>:'".to_string()).on_line(1))
+                }
+            },
+            None => return Err(ParseError::new("", "Header is missing".to_string()).on_line(1))
+        };
+        let mut cards: VecDeque<usize> = VecDeque::new();
+        for (index, s) in lines.enumerate() {
+            cards.push_back(error::parse_part(s, s, "a card").map_err(|e| e.on_line(index+2))?);
+        }
+        Ok(Player {
+            name,
+            cards
+        })
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:", self.name)?;
+        for card in self.cards.iter() {
+            write!(f, "\n{}", card)?;
+        }
+        Ok(())
+    }
+}
+
+impl Player {
+    fn has_no_cards(&self) -> bool {
+        self.cards.len() == 0
+    }
+
+    fn calculate_score(&self) -> Number {
+        let mut score = Number::zero();
+        let mut value: usize = self.cards.len();
+        for card in self.cards.iter() {
+            score += Number::from(*card) * value;
+            value -= 1;
+        }
+        score
+    }
+
+    /// A copy of the cards under the top card, which is being played
+    fn sub_deck(&self, take_cards: usize) -> Player {
+        Player {
+            name: self.name.clone(),
+            cards: self.cards.iter().skip(1).take(take_cards).cloned().collect()
+        }
+    }
+}
+
+struct RecursiveCombat {
+    round: usize,
+    previous_rounds: HashMap<(Player,Player),usize>, // map from (player1,player2) to round
+    player1: Player,
+    player2: Player
+}
+
+impl RecursiveCombat {
+    fn new(player1: Player, player2: Player) -> Self {
+        RecursiveCombat {
+            round: 0,
+            previous_rounds: HashMap::new(),
+            player1,
+            player2
+        }
+    }
+
+    /// Plays a round, including any sub-games, where task counts every round played in any game.
+    /// Nothing changes until the winner is known, so the game is still whole if a sub-game gives up.
+    fn play_round(&mut self, task: &mut Task) -> Result<(), Error> {
+        task.tick()?;
+        // deal the cards
+        let card1 = *self.player1.cards.front().expect("Cannot play if player1 has no cards");
+        let card2 = *self.player2.cards.front().expect("Cannot play if player2 has no cards");
+        // find the winnder
+        let winner_name: String;
+        if self.player1.cards.len() > card1 && self.player2.cards.len() > card2 {
+            // recurse
+            let mut sub_game = RecursiveCombat::new(self.player1.sub_deck(card1), self.player2.sub_deck(card2));
+            loop {
+                if let Some(sub_winner) = sub_game.has_winner() {
+                    debug!("{} wins sub-game after {} rounds!", sub_winner.name, sub_game.round);
+                    winner_name = sub_winner.name.clone();
+                    break;
+                }
+                sub_game.play_round(task)?;
+            }
+        } else {
+            // like non-recursive combat
+            assert_ne!(card1, card2);
+            if card1 > card2 {
+                winner_name = self.player1.name.clone();
+            } else {
+                winner_name = self.player2.name.clone();
+            }
+        }
+        // save previous state
+        self.previous_rounds.insert((self.player1.clone(),self.player2.clone()), self.round);
+        // start this round
+        self.round += 1;
+        self.player1.cards.pop_front();
+        self.player2.cards.pop_front();
+        // put cards in winners deck
+        if winner_name == self.player1.name {
+            self.player1.cards.push_back(card1);
+            self.player1.cards.push_back(card2);
+        } else {
+            self.player2.cards.push_back(card2);
+            self.player2.cards.push_back(card1);
+        }
+        Ok(())
+    }
+
+    /// Only saves the top level game, between rounds, as sub-games are always much shorter
+    fn save(&self, checkpoint: &mut Checkpoint) {
+        checkpoint.set("player1", &self.player1.name);
+        checkpoint.set_list("cards1", self.player1.cards.iter());
+        checkpoint.set("player2", &self.player2.name);
+        checkpoint.set_list("cards2", self.player2.cards.iter());
+        for ((player1, player2), round) in self.previous_rounds.iter() {
+            let cards = |player: &Player| player.cards.iter().map(|c| c.to_string()).collect::<Vec<String>>().join(" ");
+            checkpoint.set("previous", format!("{} / {} / {}", round, cards(player1), cards(player2)));
+        }
+    }
+
+    fn restore(checkpoint: &Checkpoint) -> Result<Self, Error> {
+        let player1 = Player { name: checkpoint.get("player1")?, cards: checkpoint.get_list("cards1")?.into_iter().collect() };
+        let player2 = Player { name: checkpoint.get("player2")?, cards: checkpoint.get_list("cards2")?.into_iter().collect() };
+        let mut previous_rounds = HashMap::new();
+        for previous in checkpoint.get_all("previous") {
+            let parts: Vec<&str> = previous.split(" / ").collect();
+            if parts.len() != 3 {
+                return Err(ParseError::new(previous, "Expected '<round> / <cards1> / <cards2>'".to_string()).into());
+            }
+            let cards = |part: &str| part.split_whitespace().map(|c| error::parse_part(previous, c, "a card")).collect::<Result<VecDeque<usize>, _>>();
+            previous_rounds.insert((
+                Player { name: player1.name.clone(), cards: cards(parts[1])? },
+                Player { name: player2.name.clone(), cards: cards(parts[2])? }
+            ), error::parse_part(previous, parts[0], "a round")?);
+        }
+        Ok(RecursiveCombat {
+            round: checkpoint.progress()?,
+            previous_rounds,
+            player1,
+            player2
+        })
+    }
+
+    fn has_winner(&self) -> Option<&Player> {
+        if self.player1.has_no_cards() {
+            Some(&self.player2)
+        } else if self.player2.has_no_cards() {
+            Some(&self.player1)
+        } else {
+            match self.previous_rounds.get(&(self.player1.clone(),self.player2.clone())) {
+                Some(_) => Some(&self.player1),
+                None => None
+            }
+        }
+    }
+} 
+
+pub fn solve(text: &str) -> Result<Solution, Error> {
+    let mut checkpointer = Checkpointer::new("day22 part2", text);
+    let mut game = match checkpointer.resume(None)? {
+        Some(checkpoint) => RecursiveCombat::restore(&checkpoint)?,
+        None => {
+            let mut players: VecDeque<Player> = input::parse_blocks(text)?.into_iter().collect();
+            if players.len() != 2 {
+                return Err(ParseError::new("", format!("Expected 2 players but found {}", players.len())).into());
+            }
+            RecursiveCombat::new(players.pop_front().unwrap(), players.pop_front().unwrap())
+        }
+    };
+    check_cards(&game.player1, &game.player2)?;
+    let mut task = Task::new("playing recursive combat");
+    loop {
+        if let Some(winner) = game.has_winner() {
+            info!("{} wins after {} rounds!", winner.name, game.round);
+            info!("{}", winner);
+            return Ok(Solution::new(winner.calculate_score(), Json::object(vec![
+                ("winner", winner.name.as_str().into()),
+                ("rounds", game.round.into()),
+                ("deck", winner.cards.iter().cloned().collect::<Vec<usize>>().into())
+            ])));
+        }
+        let stop = game.play_round(&mut task);
+        checkpointer.check(stop, game.round, |checkpoint| game.save(checkpoint))?;
+    }
+}
+
+/// Every card must be different, as cards change hands and two the same would make a round with no winner
+fn check_cards(player1: &Player, player2: &Player) -> Result<(), Error> {
+    let mut cards: Vec<&usize> = player1.cards.iter().chain(player2.cards.iter()).collect();
+    cards.sort();
+    match cards.windows(2).find(|pair| pair[0] == pair[1]) {
+        Some(pair) => Err(Error::Solve(format!("There is more than one card {}", pair[0]))),
+        None => Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::gen::Rng;
+    use super::Player;
+
+    #[test]
+    fn display_round_trips() {
+        let mut rng = Rng::new(22);
+        for _ in 0..100 {
+            let mut cards: Vec<usize> = (1..=rng.range(1, 50)).collect();
+            rng.shuffle(&mut cards);
+            cards.truncate(rng.range(0, cards.len()));
+            let text = format!("Player {}:{}", rng.range(1, 2), cards.iter().map(|card| format!("\n{}", card)).collect::<String>());
+            let shown = text.parse::<Player>().unwrap().to_string();
+            assert_eq!(shown, text);
+            assert_eq!(shown.parse::<Player>().unwrap().to_string(), shown);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use common::gen::{Generated, Rng};
+
+/// The labels of the 9 cups in a random order (size is ignored, since part 2 always adds cups up to a
+/// million), with no known answers, since the only way to find out where the cups end up is to move
+/// them
+pub fn generate(rng: &mut Rng, _size: Option<usize>) -> Generated {
+    let mut cups: Vec<usize> = (1..=9).collect();
+    rng.shuffle(&mut cups);
+    Generated::new(cups.iter().map(|c| c.to_string()).collect())
+        .param(Some(1), "moves", 100)
+        .param(Some(2), "moves", 10000000)
+}
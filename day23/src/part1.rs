@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::fmt;
+use common::error::{Error, ParseError};
+use common::run::Task;
+use common::{debug, info};
+
+struct CupCircle {
+    cups: VecDeque<usize>,
+}
+
+impl FromStr for CupCircle {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut cups: VecDeque<usize> = VecDeque::new();
+        for (index, c) in line.chars().enumerate() {
+            cups.push_back(match c.to_digit(10) {
+                Some(digit) if digit > 0 && !cups.contains(&(digit as usize)) => digit as usize,
+                _ => return Err(ParseError::new(line, format!("Expected a unique cup label from 1 to 9 but found '{}'", c)).at_column(index + 1))
+            });
+        }
+        if cups.len() < 5 {
+            return Err(ParseError::new(line, "Expected at least 5 cups".to_string())); // so that a destination remains after taking 3 cups
+        }
+        if *cups.iter().max().unwrap() != cups.len() {
+            return Err(ParseError::new(line, format!("Expected every cup from 1 to {}", cups.len())));
+        }
+        Ok(CupCircle {
+            cups
+        })
+    }
+}
+
+impl fmt::Display for CupCircle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for cup in self.cups.iter() {
+            write!(f, "{}", cup)?;
+        }
+        Ok(())
+    }
+}
+
+impl CupCircle {
+    fn read_current(&self) -> usize {
+        *self.cups.front().unwrap()
+    }
+
+    fn move_next(&mut self) {
+        let first = self.cups.pop_front().unwrap();
+        self.cups.push_back(first);
+    }
+
+    fn move_to_value(&mut self, value: usize) {
+        while self.read_current() != value {
+            self.move_next();
+        }
+    }
+
+    fn take_cups(&mut self, number: usize) -> Vec<usize> {
+        let mut taken = Vec::new();
+        for _ in 0..number {
+            taken.push(self.cups.pop_front().unwrap());
+        }
+        taken
+    }
+
+    fn place_cups(&mut self, cups: Vec<usize>) {
+        for cup in cups.into_iter().rev() {
+            self.cups.push_front(cup);
+        }
+    }
+
+    fn find_next_lowest(&self, lower_than: &usize) -> usize { // wrap to highest if nothing lower
+        let mut lower_numbers: Vec<&usize> = self.cups.iter().filter(|c| *c < lower_than).collect();
+        lower_numbers.sort();
+        match lower_numbers.last() {
+            Some(next_lowest) => **next_lowest,
+            None => *self.cups.iter().max().unwrap()
+        }
+    }
+
+    fn cup_string(&self) -> String { // excluding current cup
+        let mut s = String::new();
+        for cup in self.cups.iter().skip(1) {
+            s.push_str(&format!("{}",cup));
+        }
+        s
+    }
+}
+
+pub fn solve(text: &str, moves: usize) -> Result<String, Error> {
+    let mut circle: CupCircle = text.trim().parse().map_err(|e: ParseError| e.on_line(1))?;
+    let mut task = Task::new("moving cups");
+    for m in 0..moves {
+        task.tick()?;
+        debug!("Before move {}: {}", m+1, circle);
+        let current = circle.read_current();
+        circle.move_next(); // so we dont take the current
+        let taken_cups = circle.take_cups(3);
+        debug!("Taken: {:?}", taken_cups);
+        let destination = circle.find_next_lowest(&current);
+        debug!("Destination: {}", destination);
+        circle.move_to_value(destination);
+        circle.move_next(); // so we insert after destination
+        circle.place_cups(taken_cups);
+        circle.move_to_value(current); // back to initial current cup
+        circle.move_next(); // next cup for next round
+        debug!("");
+    }
+    info!("Final: {}", circle);
+    circle.move_to_value(1);
+    Ok(circle.cup_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::gen::Rng;
+    use super::CupCircle;
+
+    #[test]
+    fn display_round_trips() {
+        let mut rng = Rng::new(23);
+        for _ in 0..100 {
+            let mut cups: Vec<usize> = (1..=rng.range(5, 9)).collect();
+            rng.shuffle(&mut cups);
+            let text: String = cups.iter().map(|cup| cup.to_string()).collect();
+            let shown = text.parse::<CupCircle>().unwrap().to_string();
+            assert_eq!(shown, text);
+            assert_eq!(shown.parse::<CupCircle>().unwrap().to_string(), shown);
+        }
+    }
+}
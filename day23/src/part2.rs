@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::fmt;
+use common::checkpoint::{Checkpoint, Checkpointer};
+use common::error::{Error, ParseError};
+use common::run::Task;
+use common::{debug, info, trace};
+
+/*
+Note: this took ~3hrs to run
+*/
+
+const NUMBER_OF_CUPS: usize = 1000000;
+
+struct CupCircle {
+    cups: VecDeque<usize>,
+    index: usize
+}
+
+impl FromStr for CupCircle {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut cups: VecDeque<usize> = VecDeque::new();
+        for (index, c) in line.chars().enumerate() {
+            cups.push_back(match c.to_digit(10) {
+                Some(digit) if digit > 0 && !cups.contains(&(digit as usize)) => digit as usize,
+                _ => return Err(ParseError::new(line, format!("Expected a unique cup label from 1 to 9 but found '{}'", c)).at_column(index + 1))
+            });
+        }
+        if cups.len() < 5 {
+            return Err(ParseError::new(line, "Expected at least 5 cups".to_string())); // so that a destination remains after taking 3 cups
+        }
+        if *cups.iter().max().unwrap() != cups.len() {
+            return Err(ParseError::new(line, format!("Expected every cup from 1 to {}", cups.len())));
+        }
+        for i in (cups.iter().max().unwrap()+1)..=NUMBER_OF_CUPS {
+            cups.push_back(i);
+        }
+        Ok(CupCircle {
+            cups,
+            index: 0
+        })
+    }
+}
+
+impl fmt::Display for CupCircle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, cup) in self.cups.iter().enumerate() {
+            if i == self.index {
+                write!(f, "({})", cup)?
+            } else {
+                write!(f, " {} ", cup)?
+            }
+        }
+        fmt::Result::Ok(())
+    }
+}
+
+impl CupCircle {
+    fn save(&self, checkpoint: &mut Checkpoint) {
+        checkpoint.set("index", self.index);
+        checkpoint.set_list("cups", self.cups.iter());
+    }
+
+    fn restore(checkpoint: &Checkpoint) -> Result<Self, Error> {
+        let circle = CupCircle {
+            cups: checkpoint.get_list("cups")?.into_iter().collect(),
+            index: checkpoint.get("index")?
+        };
+        if circle.cups.len() != NUMBER_OF_CUPS || circle.index >= NUMBER_OF_CUPS {
+            return Err(Error::Solve(format!("Expected a checkpoint of {} cups", NUMBER_OF_CUPS)));
+        }
+        Ok(circle)
+    }
+
+    fn read_current(&self) -> usize {
+        self.cups[self.index]
+    }
+
+    fn move_next(&mut self) {
+        self.index += 1;
+        if self.index == self.cups.len() {
+            self.index = 0;
+        }
+    }
+
+    fn find_value(&mut self, value: &usize) -> usize {
+        for (i, cup) in self.cups.iter().enumerate() {
+            if *cup == *value {
+                return i;
+            }
+        }
+        panic!("Value not found: {}", value)
+    }
+
+    fn take_cups(&mut self, after_index: usize, number: usize) -> Vec<usize> {
+        let mut taken = Vec::new();
+        let mut at_index = after_index + 1;
+        for _ in 0..number {
+            if at_index == self.cups.len() {
+                at_index = 0;
+            }
+            taken.push(self.cups.remove(at_index).unwrap());
+            if at_index < self.index {
+                self.index -= 1;
+            }
+        }
+        taken
+    }
+
+    fn place_cups(&mut self, after_index: usize, cups: Vec<usize>) {
+        if after_index < self.index {
+            self.index += cups.len();
+        }
+        let at_index = after_index + 1;
+        for cup in cups.into_iter().rev() {
+            self.cups.insert(at_index, cup);
+        }
+    }
+}
+
+pub fn solve(text: &str, moves: usize) -> Result<usize, Error> {
+    let mut checkpointer = Checkpointer::new("day23 part2", text).check_every(100000);
+    let (mut circle, first_move) = match checkpointer.resume(Some(moves))? {
+        Some(checkpoint) => (CupCircle::restore(&checkpoint)?, checkpoint.progress()?),
+        None => (text.trim().parse().map_err(|e: ParseError| e.on_line(1))?, 0)
+    };
+    let mut task = Task::new("moving cups");
+    for m in first_move..moves {
+        checkpointer.tick(&mut task, m, |checkpoint| circle.save(checkpoint))?;
+        if m % 1000 == 0 {
+            debug!("Move {}", m+1);
+        }
+        trace!("Before move {}: {}", m+1, circle);
+        let current_value = circle.read_current();
+        let taken_cups = circle.take_cups(circle.index, 3);
+        trace!("Taken: {:?}", taken_cups);
+        let mut destination_value: usize = current_value - 1;
+        if destination_value == 0 {
+            destination_value = NUMBER_OF_CUPS;
+        }
+        while taken_cups.contains(&destination_value) {
+            destination_value -= 1;
+            if destination_value == 0 {
+                destination_value = NUMBER_OF_CUPS;
+            }
+        }
+        trace!("Destination: {}", destination_value);
+        let destination_index = circle.find_value(&destination_value);
+        circle.place_cups(destination_index, taken_cups);
+        circle.move_next(); // next cup for next round
+        trace!("");
+    }
+    trace!("Final: {}", circle);
+    let index_of_one = circle.find_value(&1);
+    circle.index = index_of_one;
+    circle.move_next(); // dont read 1
+    let n1 = circle.read_current();
+    circle.move_next();
+    let n2 = circle.read_current();
+    info!("Next 2 cups: {}, {}", n1, n2);
+    Ok(n1 * n2)
+}
@@ -0,0 +1,31 @@
+use std::env;
+use common::answer::Solution;
+use common::checkpoint;
+use common::input;
+use common::log;
+use common::output;
+use common::params::Params;
+use common::render;
+use common::run;
+use day24::part2;
+
+const USAGE: &str = "Please provide arguments: Filename... [--days <value>] [--checkpoint <file>] [--checkpoint-every <seconds>] [--resume <file>] [--render <file.png|ppm|svg> [--frames]] [--timeout <seconds>] [--format text|json] [-q|-v|-vv]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    log::take_verbosity(&mut args);
+    let format = output::or_usage(output::take_format(&mut args), USAGE);
+    output::or_usage(checkpoint::take_flags(&mut args), USAGE);
+    output::or_usage(render::take_flags(&mut args), USAGE);
+    output::or_usage(run::take_flags(&mut args), USAGE);
+    let (files, flags) = input::split_files(&args[1..]);
+    if !files.is_empty() {
+        output::print_each(format, 24, Some(2), files, |filename, text| {
+            Params::load(filename, Some(2), flags)
+                .and_then(|params| part2(text, params.get("days")?))
+                .map(Solution::from)
+        });
+    } else {
+        println!("{}", USAGE);
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use common::gen::{Generated, Rng};
+
+/// Directions and their offsets in axial coordinates (east, south east)
+const DIRECTIONS: [(&str, (isize, isize)); 6] = [("e", (1, 0)), ("se", (0, 1)), ("sw", (-1, 1)), ("w", (-1, 0)), ("nw", (0, -1)), ("ne", (1, -1))];
+
+/// Size lines of directions (400 by default), a third of which lead back to a tile flipped before by
+/// another path, where each path is a random walk followed by the way to the tile, shuffled
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(400);
+    let mut flips: HashMap<(isize, isize), usize> = HashMap::new();
+    let mut targets: Vec<(isize, isize)> = Vec::new();
+    let mut lines = Vec::new();
+    for _ in 0..size {
+        let target = if !targets.is_empty() && rng.chance(1, 3) {
+            *rng.pick(&targets)
+        } else {
+            let mut tile = (0, 0);
+            for _ in 0..rng.range(5, 20) {
+                tile = step(tile, rng.pick(&DIRECTIONS).1);
+            }
+            tile
+        };
+        targets.push(target);
+        *flips.entry(target).or_insert(0) += 1;
+        lines.push(path_to(rng, target).concat());
+    }
+    let black = flips.values().filter(|count| *count % 2 == 1).count();
+    Generated::new(lines.join("\n"))
+        .param(Some(2), "days", 100)
+        .answer(1, black)
+}
+
+fn step((e, se): (isize, isize), (de, dse): (isize, isize)) -> (isize, isize) {
+    (e + de, se + dse)
+}
+
+/// The directions of a random walk from the reference tile, then the shortest way from there to the
+/// target, in a random order (which makes no difference to where they end up)
+fn path_to(rng: &mut Rng, target: (isize, isize)) -> Vec<&'static str> {
+    let mut directions = Vec::new();
+    let mut tile = (0, 0);
+    for _ in 0..rng.range(2, 10) {
+        let (name, offset) = rng.pick(&DIRECTIONS);
+        directions.push(*name);
+        tile = step(tile, *offset);
+    }
+    while tile != target {
+        let (e, se) = (target.0 - tile.0, target.1 - tile.1);
+        let name = match (e.signum(), se.signum()) {
+            (1, -1) => "ne",
+            (-1, 1) => "sw",
+            (1, _) => "e",
+            (-1, _) => "w",
+            (_, 1) => "se",
+            _ => "nw"
+        };
+        directions.push(name);
+        tile = step(tile, DIRECTIONS.iter().find(|(n, _)| *n == name).unwrap().1);
+    }
+    rng.shuffle(&mut directions);
+    directions
+}
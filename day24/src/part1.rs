@@ -1,10 +1,10 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs;
 use std::str::FromStr;
 use std::ops::AddAssign;
-
-const NEW_LINE: &str = "\r\n";
+use common::input;
+use common::error::{Error, ParseError};
+use common::grid::Grid;
+use common::render::{self, Picture, Rgb};
 
 #[derive(Debug, Copy, Clone)]
 enum Direction {
@@ -90,32 +90,32 @@ impl Floor {
 struct Directions(Vec<Direction>);
 
 impl FromStr for Directions {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(line: &str) -> Result<Self, Self::Err> {
         let mut directions = Vec::new();
-        let mut chars = line.chars();
-        while let Some(c) = chars.next() {
+        let mut chars = line.chars().enumerate();
+        while let Some((index, c)) = chars.next() {
             directions.push(match c {
                 'e' => Direction::East,
                 'w' => Direction::West,
                 'n' => match chars.next() {
-                    Some(c2) => match c2 {
+                    Some((index2, c2)) => match c2 {
                         'e' => Direction::NorthEast,
                         'w' => Direction::NorthWest,
-                        _ => return Err(format!("Invalid 2nd character after '{}': {}", c, c2))
+                        _ => return Err(ParseError::new(line, format!("Invalid 2nd character after '{}': {}", c, c2)).at_column(index2 + 1))
                     },
-                    None => return Err(format!("Missing 2nd character after '{}'", c))
+                    None => return Err(ParseError::new(line, format!("Missing 2nd character after '{}'", c)).at_column(index + 1))
                 },
                 's' => match chars.next() {
-                    Some(c2) => match c2 {
+                    Some((index2, c2)) => match c2 {
                         'e' => Direction::SouthEast,
                         'w' => Direction::SouthWest,
-                        _ => return Err(format!("Invalid 2nd character after '{}': {}", c, c2))
+                        _ => return Err(ParseError::new(line, format!("Invalid 2nd character after '{}': {}", c, c2)).at_column(index2 + 1))
                     },
-                    None => return Err(format!("Missing 2nd character after '{}'", c))
+                    None => return Err(ParseError::new(line, format!("Missing 2nd character after '{}'", c)).at_column(index + 1))
                 },
-                _ => return Err(format!("Invalid 1st character: {}", c))
+                _ => return Err(ParseError::new(line, format!("Invalid 1st character: {}", c)).at_column(index + 1))
             });
         }
         Ok(Directions(directions))
@@ -132,21 +132,34 @@ impl Directions {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let tiles_to_flip: Vec<Directions> = text.split(NEW_LINE).map(|s| s.parse()
-            .expect(&format!("Error parsing Directions: {}", s))).collect();
-        let mut floor = Floor::new();
-        for directions in tiles_to_flip {
-            let point = directions.point();
-            floor.flip_tile(point);
-        }
-        println!("Result: {}", floor.count_black());
-    } else {
-        println!("Please provide 1 argument: Filename");
+pub fn solve(text: &str) -> Result<usize, Error> {
+    let tiles_to_flip: Vec<Directions> = input::parse_lines(text)?;
+    let mut floor = Floor::new();
+    for directions in tiles_to_flip {
+        let point = directions.point();
+        floor.flip_tile(point);
     }
-}
\ No newline at end of file
+    render::save(|| to_picture(floor.0.iter().filter(|(_point, tile)| tile.color == Color::Black).map(|(point, _tile)| point)))?;
+    Ok(floor.count_black())
+}
+
+/// The floor as hexagons, with a border of white tiles around the black ones
+fn to_picture<'a, I: Iterator<Item = &'a Point>>(black: I) -> Picture {
+    // a point is (north west, west), so its axial coordinates are (east, south east), which become
+    // rows of hexagons where odd rows are shifted right
+    let tiles: Vec<(isize, isize)> = black.map(|Point(north_west, west)| {
+        let (east, south_east) = (-west, -north_west);
+        (south_east, east + (south_east - south_east.rem_euclid(2)) / 2)
+    }).collect();
+    let mut top = tiles.iter().map(|(row, _)| *row).min().unwrap_or(0) - 1;
+    top -= top.rem_euclid(2); // keeping odd rows odd
+    let bottom = tiles.iter().map(|(row, _)| *row).max().unwrap_or(0) + 1;
+    let left = tiles.iter().map(|(_, col)| *col).min().unwrap_or(0) - 1;
+    let right = tiles.iter().map(|(_, col)| *col).max().unwrap_or(0) + 1;
+    let mut cells = Grid::new((right - left + 1) as usize, (bottom - top + 1) as usize, Rgb::WHITE);
+    for (row, col) in tiles {
+        cells[((row - top) as usize, (col - left) as usize)] = Rgb(30, 30, 30);
+    }
+    Picture::new(cells).hexagonal().background(Rgb::GREY)
+}
+
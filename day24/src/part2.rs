@@ -0,0 +1,205 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+use common::input;
+use common::checkpoint::{Checkpoint, Checkpointer};
+use common::error::{self, Error, ParseError};
+use common::automaton::{Automaton, Rule};
+use common::grid::Grid;
+use common::render::{self, Picture, Rgb};
+use common::run::Task;
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast
+}
+
+impl Direction {
+    fn offset(&self) -> Point {
+        match self {
+            Direction::East => Point(0, -1),
+            Direction::SouthEast => Point(-1, 0),
+            Direction::SouthWest => Point(-1, 1),
+            Direction::West => Point(0, 1),
+            Direction::NorthWest => Point(1, 0),
+            Direction::NorthEast => Point(1, -1)
+        }
+    }
+
+    fn options() -> Vec<Direction> {
+        vec![
+            Direction::East,
+            Direction::SouthEast,
+            Direction::SouthWest,
+            Direction::West,
+            Direction::NorthWest,
+            Direction::NorthEast
+        ]
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Point(isize, isize);
+
+impl Point {
+    fn move_in_direction(&self, direction: &Direction) -> Self {
+        let offset = direction.offset();
+        Point(self.0 + offset.0, self.1 + offset.1)
+    }
+
+    fn adjacent(&self) -> Vec<Point> {
+        Direction::options().iter().map(|direction| self.move_in_direction(direction)).collect()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.split_once(',') {
+            Some((a, b)) => Ok(Point(error::parse_part(text, a, "a coordinate")?, error::parse_part(text, b, "a coordinate")?)),
+            None => Err(ParseError::new(text, "Expected '<a>,<b>'".to_string()))
+        }
+    }
+}
+
+/// The black tiles, as every other tile is white
+struct Floor {
+    exhibit: Automaton<Point, fn(&Point) -> Vec<Point>>
+}
+
+impl Floor {
+    fn new(black: BTreeSet<Point>, day: usize) -> Self {
+        // white tiles with exactly 2 adjacent black tiles become black, and black tiles stay black with 1 or 2
+        Floor {
+            exhibit: Automaton::new(black, Point::adjacent as fn(&Point) -> Vec<Point>, Rule::new(&[2], &[1, 2])).at_generation(day)
+        }
+    }
+
+    fn flip_tiles(tiles_to_flip: Vec<Directions>) -> Self {
+        let mut black = BTreeSet::new();
+        for directions in tiles_to_flip {
+            let point = directions.point();
+            if !black.remove(&point) {
+                black.insert(point);
+            }
+        }
+        Floor::new(black, 0)
+    }
+
+    fn run_daily(&mut self) {
+        self.exhibit.step();
+    }
+
+    fn day(&self) -> usize {
+        self.exhibit.generation()
+    }
+
+    fn count_black(&self) -> usize {
+        self.exhibit.count()
+    }
+
+    fn save(&self, checkpoint: &mut Checkpoint) {
+        checkpoint.set_list("black", self.exhibit.live().iter());
+    }
+
+    fn restore(checkpoint: &Checkpoint) -> Result<Self, Error> {
+        Ok(Floor::new(checkpoint.get_list("black")?.into_iter().collect(), checkpoint.progress()?))
+    }
+}
+
+#[derive(Debug)]
+struct Directions(Vec<Direction>);
+
+impl FromStr for Directions {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut directions = Vec::new();
+        let mut chars = line.chars().enumerate();
+        while let Some((index, c)) = chars.next() {
+            directions.push(match c {
+                'e' => Direction::East,
+                'w' => Direction::West,
+                'n' => match chars.next() {
+                    Some((index2, c2)) => match c2 {
+                        'e' => Direction::NorthEast,
+                        'w' => Direction::NorthWest,
+                        _ => return Err(ParseError::new(line, format!("Invalid 2nd character after '{}': {}", c, c2)).at_column(index2 + 1))
+                    },
+                    None => return Err(ParseError::new(line, format!("Missing 2nd character after '{}'", c)).at_column(index + 1))
+                },
+                's' => match chars.next() {
+                    Some((index2, c2)) => match c2 {
+                        'e' => Direction::SouthEast,
+                        'w' => Direction::SouthWest,
+                        _ => return Err(ParseError::new(line, format!("Invalid 2nd character after '{}': {}", c, c2)).at_column(index2 + 1))
+                    },
+                    None => return Err(ParseError::new(line, format!("Missing 2nd character after '{}'", c)).at_column(index + 1))
+                },
+                _ => return Err(ParseError::new(line, format!("Invalid 1st character: {}", c)).at_column(index + 1))
+            });
+        }
+        Ok(Directions(directions))
+    }
+}
+
+impl Directions {
+    fn point(&self) -> Point {
+        let mut point = Point(0, 0);
+        for direction in self.0.iter() {
+            point = point.move_in_direction(direction);
+        }
+        point
+    }
+}
+
+pub fn solve(text: &str, days: usize) -> Result<usize, Error> {
+    let mut checkpointer = Checkpointer::new("day24 part2", text);
+    let mut floor = match checkpointer.resume(Some(days))? {
+        Some(checkpoint) => Floor::restore(&checkpoint)?,
+        None => Floor::flip_tiles(input::parse_lines(text)?) // initial state
+    };
+    render::save_frame(floor.day(), || to_picture(floor.exhibit.live().iter()))?;
+    // day simulation
+    let mut task = Task::new("flipping tiles").check_every(1);
+    while floor.day() < days {
+        checkpointer.tick(&mut task, floor.day(), |checkpoint| floor.save(checkpoint))?;
+        floor.run_daily();
+        render::save_frame(floor.day(), || to_picture(floor.exhibit.live().iter()))?;
+    }
+    render::save(|| to_picture(floor.exhibit.live().iter()))?;
+    Ok(floor.count_black())
+}
+
+/// The floor as hexagons, with a border of white tiles around the black ones
+fn to_picture<'a, I: Iterator<Item = &'a Point>>(black: I) -> Picture {
+    // a point is (north west, west), so its axial coordinates are (east, south east), which become
+    // rows of hexagons where odd rows are shifted right
+    let tiles: Vec<(isize, isize)> = black.map(|Point(north_west, west)| {
+        let (east, south_east) = (-west, -north_west);
+        (south_east, east + (south_east - south_east.rem_euclid(2)) / 2)
+    }).collect();
+    let mut top = tiles.iter().map(|(row, _)| *row).min().unwrap_or(0) - 1;
+    top -= top.rem_euclid(2); // keeping odd rows odd
+    let bottom = tiles.iter().map(|(row, _)| *row).max().unwrap_or(0) + 1;
+    let left = tiles.iter().map(|(_, col)| *col).min().unwrap_or(0) - 1;
+    let right = tiles.iter().map(|(_, col)| *col).max().unwrap_or(0) + 1;
+    let mut cells = Grid::new((right - left + 1) as usize, (bottom - top + 1) as usize, Rgb::WHITE);
+    for (row, col) in tiles {
+        cells[((row - top) as usize, (col - left) as usize)] = Rgb(30, 30, 30);
+    }
+    Picture::new(cells).hexagonal().background(Rgb::GREY)
+}
+
@@ -0,0 +1,31 @@
+use common::gen::{Generated, Rng};
+
+const SUBJECT_DIVIDER: u64 = 20201227;
+const HANDSHAKE_INITIAL: u64 = 7;
+
+/// The public keys of a card and a door with random loop sizes up to size (1000000 by default), where
+/// the encryption key is the subject number transformed by both loop sizes
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(1000000).clamp(1, SUBJECT_DIVIDER as usize - 2);
+    let card_loop = rng.range(1, size) as u64;
+    let door_loop = rng.range(1, size) as u64;
+    let card_key = power(HANDSHAKE_INITIAL, card_loop);
+    let door_key = power(HANDSHAKE_INITIAL, door_loop);
+    Generated::new(format!("{}\n{}", card_key, door_key))
+        .answer(1, power(door_key, card_loop) as usize)
+}
+
+/// Transforms the subject number with a loop size, by squaring rather than looping
+fn power(subject: u64, loop_size: u64) -> u64 {
+    let mut result = 1;
+    let mut base = subject % SUBJECT_DIVIDER;
+    let mut exponent = loop_size;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = result * base % SUBJECT_DIVIDER;
+        }
+        base = base * base % SUBJECT_DIVIDER;
+        exponent /= 2;
+    }
+    result
+}
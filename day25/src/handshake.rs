@@ -0,0 +1,74 @@
+use common::input;
+use common::error::{Error, ParseError};
+use common::run::Task;
+
+const SUBJECT_DIVIDER: usize = 20201227;
+const HANDSHAKE_INITIAL: usize = 7;
+
+struct SubjectNumber {
+    subject_number: usize,
+    current_value: usize
+}
+
+impl SubjectNumber {
+    fn transform(&mut self) {
+        self.current_value = (self.current_value * self.subject_number) % SUBJECT_DIVIDER;
+    }
+
+    fn transform_loop(&mut self, loop_size: usize) {
+        for _ in 0..loop_size {
+            self.transform();
+        }
+    }
+
+    fn new(subject_number: usize) -> Self {
+        SubjectNumber {
+            subject_number,
+            current_value: 1
+        }
+    }
+}
+
+struct Handshake {
+    card_pub_key: usize,
+    door_pub_key: usize
+}
+
+impl Handshake {
+    fn find_encryption_key(self) -> Result<usize, Error> {
+        let mut subject_pub = SubjectNumber::new(HANDSHAKE_INITIAL);
+        let mut loop_size: usize = 0;
+        // every value repeats within SUBJECT_DIVIDER transforms, so a key not found by then never will be
+        let mut task = Task::new("searching for a loop size").limit(SUBJECT_DIVIDER, "neither public key can be made from the subject number 7");
+        loop {
+            task.tick()?;
+            subject_pub.transform();
+            loop_size += 1;
+            if subject_pub.current_value == self.card_pub_key {
+                let mut subject_enc = SubjectNumber::new(self.door_pub_key);
+                subject_enc.transform_loop(loop_size);
+                return Ok(subject_enc.current_value);
+            }
+            if subject_pub.current_value == self.door_pub_key {
+                let mut subject_enc = SubjectNumber::new(self.card_pub_key);
+                subject_enc.transform_loop(loop_size);
+                return Ok(subject_enc.current_value);
+            }
+        }
+    }
+}
+
+pub fn solve(text: &str) -> Result<usize, Error> {
+    let pub_keys: Vec<usize> = input::parse_numbers(text)?;
+    if pub_keys.len() != 2 {
+        return Err(ParseError::new("", format!("Expected 2 public keys but found {}", pub_keys.len())).into());
+    }
+    if let Some(key) = pub_keys.iter().find(|key| **key >= SUBJECT_DIVIDER) {
+        return Err(ParseError::new("", format!("Public key {} can't be made by a transform, which is always below {}", key, SUBJECT_DIVIDER)).into());
+    }
+    let handshake = Handshake {
+        card_pub_key: pub_keys[0],
+        door_pub_key: pub_keys[1]
+    };
+    handshake.find_encryption_key()
+}
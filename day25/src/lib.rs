@@ -0,0 +1,9 @@
+pub mod gen;
+pub mod handshake;
+
+use common::answer::Answer;
+use common::error::Error;
+
+pub fn part1(text: &str) -> Result<Answer, Error> {
+    Ok(handshake::solve(text)?.into())
+}
@@ -1,6 +0,0 @@
-@echo off
-.\target\debug\part2.exe "%1" 1 1
-.\target\debug\part2.exe "%1" 3 1
-.\target\debug\part2.exe "%1" 5 1
-.\target\debug\part2.exe "%1" 7 1
-.\target\debug\part2.exe "%1" 1 2
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::env;
+use common::input;
+use common::log;
+use common::output;
+use common::params::Params;
+use common::run;
+use day3::part2;
+use day3::slope::Slope;
+
+const USAGE: &str = "Please provide arguments: Filename... [--within <right>/<down>] [--rasterize true] [--timeout <seconds>] [--format text|json] [-q|-v|-vv]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+    log::take_verbosity(&mut args);
+    let format = output::or_usage(output::take_format(&mut args), USAGE);
+    output::or_usage(run::take_flags(&mut args), USAGE);
+    let (files, flags) = input::split_files(&args[1..]);
+    if !files.is_empty() {
+        output::print_each(format, 3, None, files, |_filename, text| {
+            let mut params = Params::none();
+            params.override_with(flags)?;
+            let within: Slope = params.get_or("within", Slope { right: 7, down: 2 })?;
+            part2::search(text, within.right, within.down, params.get_or("rasterize", false)?)
+        });
+    } else {
+        println!("{}", USAGE);
+    }
+}
@@ -0,0 +1,22 @@
+use common::gen::{Generated, Rng};
+use common::grid::Grid;
+
+const WIDTH: usize = 31;
+
+/// A map of size rows (323 by default) with trees scattered over a quarter of it, along with a few
+/// random slopes for part 2
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(323).max(1);
+    let map: Grid<bool> = Grid::new(WIDTH, size, false).map(|_, _| rng.chance(1, 4));
+    let slopes: Vec<(usize, usize)> = (0..rng.range(1, 5)).map(|_| (rng.range(1, 7), rng.range(1, 2))).collect();
+    let product: usize = slopes.iter().map(|(right, down)| count_trees(&map, *right, *down)).product();
+    let slopes: Vec<String> = slopes.iter().map(|(right, down)| format!("{}/{}", right, down)).collect();
+    Generated::new(map.render(|tree| if *tree { '#' } else { '.' }))
+        .param(Some(2), "slopes", slopes.join(","))
+        .answer(1, count_trees(&map, 3, 1))
+        .answer(2, product)
+}
+
+fn count_trees(map: &Grid<bool>, right: usize, down: usize) -> usize {
+    (0..map.height()).step_by(down).enumerate().filter(|(step, row)| *map.get_wrapped(*row, step * right)).count()
+}
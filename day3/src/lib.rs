@@ -0,0 +1,21 @@
+pub mod gen;
+pub mod part1;
+pub mod part2;
+pub mod slope;
+
+use common::answer::{Answer, Solution};
+use common::error::Error;
+use slope::Slope;
+
+pub fn part1(text: &str) -> Result<Answer, Error> {
+    Ok(part1::solve(text)?.into())
+}
+
+pub fn part2(text: &str, slopes: &[Slope], rasterize: bool) -> Result<Answer, Error> {
+    Ok(part2_solution(text, slopes, rasterize)?.answer)
+}
+
+/// The answer to part 2 along with the trees hit on each slope
+pub fn part2_solution(text: &str, slopes: &[Slope], rasterize: bool) -> Result<Solution, Error> {
+    part2::solve(text, slopes, rasterize)
+}
@@ -0,0 +1,11 @@
+use common::error::Error;
+use common::render;
+use crate::slope::{self, Slope};
+
+const SLOPE: Slope = Slope { right: 3, down: 1 };
+
+pub fn solve(text: &str) -> Result<usize, Error> {
+    let map = slope::parse_map(text)?;
+    render::save_text(|| slope::render_path(&map, &SLOPE, false))?;
+    Ok(slope::count_trees(&map, &[SLOPE], false)?[0])
+}
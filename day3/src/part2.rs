@@ -0,0 +1,45 @@
+use common::answer::Solution;
+use common::error::Error;
+use common::json::Json;
+use common::number::Number;
+use common::render;
+use common::info;
+use crate::slope::{self, Slope};
+
+/// The product of the trees hit on each of the slopes, along with how many each of them hit, where
+/// rasterize follows the line of each slope through every row, rather than jumping down like the puzzle
+pub fn solve(text: &str, slopes: &[Slope], rasterize: bool) -> Result<Solution, Error> {
+    let map = slope::parse_map(text)?;
+    render::save_text(|| slopes.iter().map(|slope| format!("Slope {}:\n{}", slope, slope::render_path(&map, slope, rasterize))).collect::<Vec<String>>().join("\n"))?;
+    let trees = slope::count_trees(&map, slopes, rasterize)?;
+    for (slope, count) in slopes.iter().zip(trees.iter()) {
+        info!("Slope {} hits {} trees", slope, count);
+    }
+    let product: Number = trees.iter().map(|count| Number::from(*count)).product();
+    let details: Vec<Json> = slopes.iter().zip(trees.iter()).map(|(slope, count)| Json::object(vec![
+        ("right", (slope.right as i128).into()),
+        ("down", slope.down.into()),
+        ("trees", (*count).into())
+    ])).collect();
+    Ok(Solution::new(product, Json::object(vec![
+        ("slopes", details.into())
+    ])))
+}
+
+/// The slope with right from -max_right (left) to max_right and down from 1 to max_down which hits
+/// the fewest trees, preferring the one which goes down least, then furthest left
+pub fn search(text: &str, max_right: isize, max_down: usize, rasterize: bool) -> Result<Solution, Error> {
+    if max_right < 0 {
+        return Err(Error::Solve(format!("The search already goes both left and right, so the furthest right can't be negative: {}", max_right)));
+    }
+    let map = slope::parse_map(text)?;
+    let slopes: Vec<Slope> = (1..=max_down).flat_map(|down| (-max_right..=max_right).map(move |right| Slope { right, down })).collect();
+    let trees = slope::count_trees(&map, &slopes, rasterize)?;
+    let (slope, fewest) = slopes.iter().zip(trees.iter()).min_by_key(|(_slope, count)| **count)
+        .ok_or("There are no slopes to search")?;
+    info!("Slope {} hits the fewest trees, out of {} slopes", slope, slopes.len());
+    Ok(Solution::new(*fewest, Json::object(vec![
+        ("right", (slope.right as i128).into()),
+        ("down", slope.down.into())
+    ])))
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+use std::str::FromStr;
+use common::error::{Error, ParseError};
+use common::grid::Grid;
+use common::run::Task;
+
+/// The map of open squares and trees (true), which repeats to the right forever
+pub fn parse_map(text: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(text, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    })
+}
+
+/// How far the toboggan moves right (or left, if negative) each time it moves down, eg. 3/1, or
+/// -2/1 for 2 left per 1 down
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Slope {
+    pub right: isize,
+    pub down: usize
+}
+
+impl FromStr for Slope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Expected a slope like <right>/<down> but found '{}'", s);
+        let (right, down) = s.trim().split_once('/').ok_or_else(invalid)?;
+        let slope = Slope {
+            right: right.parse().map_err(|_| invalid())?,
+            down: down.parse().map_err(|_| invalid())?
+        };
+        if slope.down == 0 {
+            return Err(format!("The slope {} never goes down", s));
+        }
+        Ok(slope)
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.right, self.down)
+    }
+}
+
+/// A list of slopes separated by commas, eg. 1/1,3/1,5/1,7/1,1/2
+pub struct Slopes(pub Vec<Slope>);
+
+impl FromStr for Slopes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let slopes = s.split(',').map(|slope| slope.parse()).collect::<Result<Vec<Slope>, String>>()?;
+        Ok(Slopes(slopes))
+    }
+}
+
+impl Slope {
+    /// The column (before wrapping around the map) which the toboggan is in on a row, or None if it
+    /// jumps over that row. As in the puzzle, it only lands on every down'th row unless rasterize is
+    /// set, in which case it passes through every row, in the column nearest to the line of the
+    /// slope, found exactly as a fraction so that 1/3 is at columns 0, 0, 1, 1, 1, 2...
+    pub fn column(&self, row: usize, rasterize: bool) -> Option<i128> {
+        let (row, right, down) = (row as i128, self.right as i128, self.down as i128);
+        if rasterize {
+            Some((2 * row * right + down).div_euclid(2 * down)) // rounding halves to the right
+        } else if row % down == 0 {
+            Some(row / down * right)
+        } else {
+            None
+        }
+    }
+}
+
+/// The column of the map which repeats at a column of the path, which may be left of the start
+fn wrap(column: i128, width: usize) -> usize {
+    column.rem_euclid(width as i128) as usize
+}
+
+/// How many trees each slope hits, from a single pass down the rows of the map, ticking a task for
+/// each slope on each row, as day3-search can check a great many slopes
+pub fn count_trees(map: &Grid<bool>, slopes: &[Slope], rasterize: bool) -> Result<Vec<usize>, Error> {
+    let mut counts = vec![0; slopes.len()];
+    let mut task = Task::new("counting trees");
+    for row in 0..map.height() {
+        for (slope, count) in slopes.iter().zip(counts.iter_mut()) {
+            task.tick()?;
+            if let Some(column) = slope.column(row, rasterize) {
+                if *map.get_wrapped(row, wrap(column, map.width())) {
+                    *count += 1;
+                }
+            }
+        }
+    }
+    Ok(counts)
+}
+
+/// The map with the path of a slope drawn over it, like the puzzle does, as an O where the toboggan
+/// is on an open square and an X where it hits a tree, repeated as many times as the path needs
+pub fn render_path(map: &Grid<bool>, slope: &Slope, rasterize: bool) -> String {
+    let width = map.width() as i128;
+    let path: Vec<Option<i128>> = (0..map.height()).map(|row| slope.column(row, rasterize)).collect();
+    let first = path.iter().flatten().min().map_or(0, |column| column.div_euclid(width));
+    let last = path.iter().flatten().max().map_or(0, |column| column.div_euclid(width));
+    let mut text = String::new();
+    for (row, on_path) in path.iter().enumerate() {
+        for column in first * width..(last + 1) * width {
+            let tree = *map.get_wrapped(row, wrap(column, map.width()));
+            text.push(match (*on_path == Some(column), tree) {
+                (true, true) => 'X',
+                (true, false) => 'O',
+                (false, true) => '#',
+                (false, false) => '.'
+            });
+        }
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slope;
+
+    fn columns(slope: &str, rasterize: bool) -> Vec<Option<i128>> {
+        let slope: Slope = slope.parse().unwrap();
+        (0..7).map(|row| slope.column(row, rasterize)).collect()
+    }
+
+    #[test]
+    fn follows_the_slope_exactly() {
+        assert_eq!(columns("1/3", false), vec![Some(0), None, None, Some(1), None, None, Some(2)]);
+        assert_eq!(columns("1/3", true), vec![Some(0), Some(0), Some(1), Some(1), Some(1), Some(2), Some(2)]);
+        assert_eq!(columns("-2/1", true), vec![Some(0), Some(-2), Some(-4), Some(-6), Some(-8), Some(-10), Some(-12)]);
+        assert_eq!(columns("-1/2", true), vec![Some(0), Some(0), Some(-1), Some(-1), Some(-2), Some(-2), Some(-3)]);
+        assert_eq!(columns("2/4", false), vec![Some(0), None, None, None, Some(2), None, None]);
+    }
+}
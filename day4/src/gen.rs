@@ -0,0 +1,93 @@
+use common::gen::{Generated, Rng};
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+/// A batch of size passports (300 by default), each of which is missing a required field, has every
+/// field but one with an invalid value, or is entirely valid (with or without a cid)
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(300);
+    let mut passports = Vec::new();
+    let mut complete: usize = 0;
+    let mut valid: usize = 0;
+    for _ in 0..size {
+        let mut fields: Vec<(&str, String)> = REQUIRED.iter().map(|key| (*key, valid_value(rng, key))).collect();
+        if rng.chance(1, 2) {
+            fields.push(("cid", rng.range(1, 999).to_string()));
+        }
+        match rng.below(3) {
+            0 => {
+                fields.remove(rng.below(REQUIRED.len()));
+            },
+            1 => {
+                let index = rng.below(REQUIRED.len());
+                fields[index].1 = invalid_value(rng, fields[index].0);
+                complete += 1;
+            },
+            _ => {
+                complete += 1;
+                valid += 1;
+            }
+        }
+        rng.shuffle(&mut fields);
+        let mut text = String::new();
+        for (index, (key, value)) in fields.iter().enumerate() {
+            if index > 0 {
+                text.push(if rng.chance(1, 4) { '\n' } else { ' ' });
+            }
+            text.push_str(&format!("{}:{}", key, value));
+        }
+        passports.push(text);
+    }
+    Generated::new(passports.join("\n\n"))
+        .answer(1, complete)
+        .answer(2, valid)
+}
+
+fn valid_value(rng: &mut Rng, key: &str) -> String {
+    match key {
+        "byr" => rng.range(1920, 2002).to_string(),
+        "iyr" => rng.range(2010, 2020).to_string(),
+        "eyr" => rng.range(2020, 2030).to_string(),
+        "hgt" if rng.chance(1, 2) => format!("{}cm", rng.range(150, 193)),
+        "hgt" => format!("{}in", rng.range(59, 76)),
+        "hcl" => format!("#{:06x}", rng.below(0x1000000)),
+        "ecl" => rng.pick(&EYE_COLORS).to_string(),
+        "pid" => format!("{:09}", rng.below(1000000000)),
+        _ => panic!("Unknown field: {}", key)
+    }
+}
+
+fn invalid_value(rng: &mut Rng, key: &str) -> String {
+    match key {
+        "byr" => outside(rng, 1920, 2002, 20).to_string(),
+        "iyr" => outside(rng, 2010, 2020, 20).to_string(),
+        "eyr" => outside(rng, 2020, 2030, 20).to_string(),
+        "hgt" => match rng.below(4) {
+            0 => format!("{}cm", outside(rng, 150, 193, 50)),
+            1 => format!("{}in", outside(rng, 59, 76, 20)),
+            2 => rng.range(59, 193).to_string(),
+            _ => format!("{}ft", rng.range(4, 7))
+        },
+        "hcl" => match rng.below(3) {
+            0 => format!("{:06x}", rng.below(0x1000000)),
+            1 => format!("#{:05x}", rng.below(0x100000)),
+            _ => format!("#{:05x}{}", rng.below(0x100000), (b'g' + rng.below(20) as u8) as char)
+        },
+        "ecl" => rng.pick(&["xry", "zzz", "red", "gmt", "blue"]).to_string(),
+        "pid" => match rng.below(2) {
+            0 => format!("{:010}", rng.below(1000000000)),
+            _ => format!("{:08}", rng.below(100000000))
+        },
+        _ => panic!("Unknown field: {}", key)
+    }
+}
+
+/// A number up to margin below min or above max
+fn outside(rng: &mut Rng, min: usize, max: usize, margin: usize) -> usize {
+    if rng.chance(1, 2) {
+        rng.range(min - margin, min - 1)
+    } else {
+        rng.range(max + 1, max + margin)
+    }
+}
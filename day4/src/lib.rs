@@ -0,0 +1,39 @@
+pub mod gen;
+pub mod passport;
+pub mod schema;
+
+use common::answer::{Answer, Solution};
+use common::error::Error;
+use common::params::Params;
+use schema::Schema;
+
+/// The schema given by the schema parameter (a file), otherwise the default one for the part
+pub fn schema(params: &Params, part: usize) -> Result<Schema, Error> {
+    if params.contains("schema") {
+        Schema::load(&params.get_path("schema")?)
+    } else {
+        Ok(if part == 1 { schema::PART1 } else { schema::PART2 }.parse()?)
+    }
+}
+
+/// The number of passports which have every required field of the schema
+pub fn part1(text: &str, schema: &Schema) -> Result<Answer, Error> {
+    Ok(part1_solution(text, schema)?.answer)
+}
+
+/// The answer to part 1 along with what is wrong with each passport which isn't valid, and the most
+/// common reasons for that
+pub fn part1_solution(text: &str, schema: &Schema) -> Result<Solution, Error> {
+    passport::solve(text, schema)
+}
+
+/// The number of passports which have every required field of the schema, each with a valid value
+pub fn part2(text: &str, schema: &Schema) -> Result<Answer, Error> {
+    Ok(part2_solution(text, schema)?.answer)
+}
+
+/// The answer to part 2 along with what is wrong with each passport which isn't valid, and the most
+/// common reasons for that
+pub fn part2_solution(text: &str, schema: &Schema) -> Result<Solution, Error> {
+    passport::solve(text, schema)
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use common::input;
+use common::answer::Solution;
+use common::error::{Error, ParseError};
+use common::json::Json;
+use common::{debug, info};
+use crate::schema::Schema;
+
+/// How many of the most common reasons for documents being invalid are logged
+const TOP_REASONS: usize = 5;
+
+fn parse_passport(text: &str) -> Result<HashMap<String,String>, ParseError> {
+    let mut passport = HashMap::new();
+    for (index, line) in input::lines(text).enumerate() {
+        for pair in line.split(" ") {
+            let key_value: Vec<&str> = pair.split(":").collect();
+            if key_value.len() != 2 {
+                return Err(ParseError::at(line, pair, "Expected '<key>:<value>'".to_string()).on_line(index + 1));
+            }
+            passport.insert(key_value[0].to_string(), key_value[1].to_string());
+        }
+    }
+    return Ok(passport);
+}
+
+/// The number of passports (or any other documents with the same format) which the schema accepts,
+/// with every missing and invalid field of each one it doesn't in the details (and logged at -v),
+/// along with how many documents have each reason for being invalid, most common first
+pub fn solve(text: &str, schema: &Schema) -> Result<Solution, Error> {
+    let passports: Vec<HashMap<String, String>> = input::parse_blocks_with(text, parse_passport)?;
+    let mut failures = Vec::new();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for (index, passport) in passports.iter().enumerate() {
+        let validation = schema.validate(passport);
+        if validation.is_valid() {
+            continue;
+        }
+        debug!("Document {}: {}", index + 1, validation);
+        for reason in validation.reasons() {
+            *counts.entry(reason).or_default() += 1;
+        }
+        failures.push(Json::object(vec![
+            ("document", (index + 1).into()),
+            ("missing", validation.missing.clone().into()),
+            ("invalid", validation.invalid.iter().map(|invalid| invalid.to_string()).collect::<Vec<String>>().into())
+        ]));
+    }
+    let mut reasons: Vec<(String, usize)> = counts.into_iter().collect();
+    reasons.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+    info!("{} of {} documents are valid", passports.len() - failures.len(), passports.len());
+    if !reasons.is_empty() {
+        info!("The most common reasons (with how many documents have each) are:");
+    }
+    for (reason, count) in reasons.iter().take(TOP_REASONS) {
+        info!("{:>6} {}", count, reason);
+    }
+    Ok(Solution::new(passports.len() - failures.len(), Json::object(vec![
+        ("failures", failures.into()),
+        ("reasons", reasons.into_iter().map(|(reason, count)| Json::object(vec![
+            ("reason", reason.into()),
+            ("count", count.into())
+        ])).collect::<Vec<Json>>().into())
+    ])))
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use regex::Regex;
+use common::input;
+use common::error::{self, Error, ParseError};
+
+/// The schema of the passports in part 1, which only need to have the required fields
+pub const PART1: &str = include_str!("../part1.schema");
+
+/// The schema of the passports in part 2, where each required field also needs a valid value
+pub const PART2: &str = include_str!("../part2.schema");
+
+/// What the value of a field must be, when it is there
+pub enum Rule {
+    Any,
+    /// A whole number within a range, eg. `int 1920..=2002`
+    Int(i64, i64),
+    /// A whole number followed by one of the units, within the range for that unit, eg.
+    /// `units 150..=193cm 59..=76in`
+    Units(Vec<(i64, i64, String)>),
+    /// Matches the regex, which should be anchored with ^ and $ to match all of the value, eg.
+    /// `regex ^#[0-9a-f]{6}$`
+    Matches(Regex),
+    /// One of a list of values, eg. `enum amb blu brn`
+    OneOf(Vec<String>)
+}
+
+impl Rule {
+    /// Ok if the value follows the rule, otherwise the reason it doesn't, eg. `out of range 59..=76in`
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        match self {
+            Rule::Any => Ok(()),
+            Rule::Int(min, max) => check_range(value, *min, *max, ""),
+            Rule::Units(ranges) => {
+                let mut checks = ranges.iter()
+                    .filter_map(|(min, max, unit)| value.strip_suffix(unit.as_str()).map(|number| check_range(number, *min, *max, unit)));
+                match checks.next() {
+                    Some(first) => checks.fold(first, |result, check| result.or_else(|reason| check.map_err(|_| reason))),
+                    None => {
+                        let units: Vec<&str> = ranges.iter().map(|(_min, _max, unit)| unit.as_str()).collect();
+                        Err(format!("has no unit of {}", units.join(" or ")))
+                    }
+                }
+            },
+            Rule::Matches(regex) => if regex.is_match(value) {
+                Ok(())
+            } else {
+                Err(format!("doesn't match /{}/", regex))
+            },
+            Rule::OneOf(values) => if values.iter().any(|v| v == value) {
+                Ok(())
+            } else {
+                Err(format!("not one of {}", values.join(" ")))
+            }
+        }
+    }
+}
+
+fn check_range(value: &str, min: i64, max: i64, unit: &str) -> Result<(), String> {
+    match value.parse::<i64>() {
+        Ok(number) if number >= min && number <= max => Ok(()),
+        Ok(_) => Err(format!("out of range {}..={}{}", min, max, unit)),
+        Err(_) => Err(format!("not a whole number{}", if unit.is_empty() { String::new() } else { format!(" of {}", unit) }))
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Any => write!(f, "any"),
+            Rule::Int(min, max) => write!(f, "int {}..={}", min, max),
+            Rule::Units(ranges) => {
+                let ranges: Vec<String> = ranges.iter().map(|(min, max, unit)| format!("{}..={}{}", min, max, unit)).collect();
+                write!(f, "units {}", ranges.join(" "))
+            },
+            Rule::Matches(regex) => write!(f, "regex {}", regex),
+            Rule::OneOf(values) => write!(f, "enum {}", values.join(" "))
+        }
+    }
+}
+
+pub struct Field {
+    pub name: String,
+    pub required: bool,
+    pub rule: Rule
+}
+
+/// The fields a type of document has, read from a schema file, which has a line for each field like
+/// `<name> = <required|optional> [<rule>]`, where the rule is one of:
+/// - `int <min>..=<max>`
+/// - `units <min>..=<max><unit>...`
+/// - `regex <pattern>`
+/// - `enum <value>...`
+/// - `any` (the default)
+///
+/// Fields which aren't in the schema are allowed, with any value
+pub struct Schema {
+    pub fields: Vec<Field>
+}
+
+impl Schema {
+    /// Reads a schema from a file
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let file = path.display().to_string();
+        let text = input::read(path).map_err(|e| format!("Error reading from {}: {}", file, e))?;
+        Ok(text.parse::<Schema>().map_err(|e| e.in_file(&file))?)
+    }
+
+    /// Checks every field of a document, rather than stopping at the first problem
+    pub fn validate(&self, document: &HashMap<String, String>) -> Validation {
+        let mut validation = Validation {
+            missing: Vec::new(),
+            invalid: Vec::new()
+        };
+        for field in self.fields.iter() {
+            match document.get(&field.name) {
+                Some(value) => if let Err(reason) = field.rule.check(value) {
+                    validation.invalid.push(Invalid {
+                        field: field.name.clone(),
+                        value: value.clone(),
+                        reason
+                    });
+                },
+                None if field.required => validation.missing.push(field.name.clone()),
+                None => ()
+            }
+        }
+        validation
+    }
+}
+
+/// A field of a document with a value which breaks the rule for it
+pub struct Invalid {
+    pub field: String,
+    pub value: String,
+    pub reason: String
+}
+
+impl fmt::Display for Invalid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={} {}", self.field, self.value, self.reason)
+    }
+}
+
+/// Every required field which a document is missing, and every field with an invalid value, in the
+/// order of the schema
+pub struct Validation {
+    pub missing: Vec<String>,
+    pub invalid: Vec<Invalid>
+}
+
+impl Validation {
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty() && self.invalid.is_empty()
+    }
+
+    /// The reason for each problem, without the values, so that the same problem with different
+    /// documents has the same reason, eg. `missing byr` or `hgt out of range 59..=76in`
+    pub fn reasons(&self) -> Vec<String> {
+        self.problems(false)
+    }
+
+    fn problems(&self, with_values: bool) -> Vec<String> {
+        let mut problems: Vec<String> = self.missing.iter().map(|field| format!("missing {}", field)).collect();
+        problems.extend(self.invalid.iter().map(|invalid| if with_values {
+            invalid.to_string()
+        } else {
+            format!("{} {}", invalid.field, invalid.reason)
+        }));
+        problems
+    }
+}
+
+/// Each problem like the reasons but with the invalid values, eg. `hgt=190in out of range 59..=76in`
+impl fmt::Display for Validation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let problems = self.problems(true);
+        if problems.is_empty() {
+            write!(f, "valid")
+        } else {
+            write!(f, "{}", problems.join(", "))
+        }
+    }
+}
+
+impl FromStr for Schema {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut fields: Vec<Field> = Vec::new();
+        for (index, line) in input::lines(text).enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let field = parse_field(line).map_err(|e| e.on_line(index + 1))?;
+            if fields.iter().any(|f| f.name == field.name) {
+                return Err(ParseError::new(line, format!("The field '{}' is already in the schema", field.name)).on_line(index + 1));
+            }
+            fields.push(field);
+        }
+        if fields.is_empty() {
+            return Err(ParseError::new("", "Expected at least one field like '<name> = <required|optional> [<rule>]'".to_string()));
+        }
+        Ok(Schema { fields })
+    }
+}
+
+fn parse_field(line: &str) -> Result<Field, ParseError> {
+    let (name, definition) = line.split_once('=').ok_or_else(|| ParseError::new(line, "Expected '<name> = <required|optional> [<rule>]'".to_string()))?;
+    let name = name.trim();
+    if name.is_empty() || name.contains(|c: char| c.is_whitespace() || c == ':') {
+        return Err(ParseError::at(line, name, "Expected a field name".to_string()));
+    }
+    let definition = definition.trim();
+    let (presence, rule) = definition.split_once(' ').unwrap_or((definition, ""));
+    let required = match presence {
+        "required" => true,
+        "optional" => false,
+        _ => return Err(ParseError::at(line, presence, format!("Expected required or optional but found '{}'", presence)))
+    };
+    Ok(Field {
+        name: name.to_string(),
+        required,
+        rule: parse_rule(line, rule.trim())?
+    })
+}
+
+fn parse_rule(line: &str, rule: &str) -> Result<Rule, ParseError> {
+    let (kind, arguments) = rule.split_once(' ').unwrap_or((rule, ""));
+    let arguments = arguments.trim();
+    let words: Vec<&str> = arguments.split_whitespace().collect();
+    match kind {
+        "" | "any" if words.is_empty() => Ok(Rule::Any),
+        "int" if words.len() == 1 => {
+            let (min, max) = parse_range(line, words[0])?;
+            Ok(Rule::Int(min, max))
+        },
+        "units" if !words.is_empty() => words.iter().map(|word| {
+            let split = word.rfind(|c: char| c.is_ascii_digit()).map_or(0, |index| index + 1);
+            let (range, unit) = word.split_at(split);
+            if unit.is_empty() {
+                return Err(ParseError::at(line, word, "Expected a range followed by its unit, eg. 150..=193cm".to_string()));
+            }
+            let (min, max) = parse_range(line, range)?;
+            Ok((min, max, unit.to_string()))
+        }).collect::<Result<Vec<_>, _>>().map(Rule::Units),
+        "regex" if !arguments.is_empty() => Regex::new(arguments)
+            .map(Rule::Matches)
+            .map_err(|e| ParseError::at(line, arguments, format!("Invalid regex: {}", e))),
+        "enum" if !words.is_empty() => Ok(Rule::OneOf(words.iter().map(|w| w.to_string()).collect())),
+        "int" | "units" | "regex" | "enum" | "any" => Err(ParseError::at(line, rule, format!("Expected {}", match kind {
+            "int" => "a range like 'int 1920..=2002'",
+            "units" => "ranges with units like 'units 150..=193cm 59..=76in'",
+            "regex" => "a pattern like 'regex ^[0-9]{9}$'",
+            "enum" => "a list of values like 'enum amb blu brn'",
+            _ => "nothing after 'any'"
+        }))),
+        _ => Err(ParseError::at(line, kind, format!("Expected a rule of int, units, regex, enum or any but found '{}'", kind)))
+    }
+}
+
+fn parse_range(line: &str, range: &str) -> Result<(i64, i64), ParseError> {
+    let (min, max) = range.split_once("..=").ok_or_else(|| ParseError::at(line, range, "Expected a range like <min>..=<max>".to_string()))?;
+    Ok((error::parse_part(line, min, "a number")?, error::parse_part(line, max, "a number")?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::{Schema, PART2};
+
+    #[test]
+    fn checks_each_rule() {
+        let schema: Schema = PART2.parse().unwrap();
+        let rule = |name: &str| &schema.fields.iter().find(|field| field.name == name).unwrap().rule;
+        assert!(rule("byr").check("2002").is_ok() && rule("byr").check("2003").is_err());
+        assert!(rule("hgt").check("60in").is_ok() && rule("hgt").check("190cm").is_ok());
+        assert_eq!(rule("hgt").check("190in"), Err("out of range 59..=76in".to_string()));
+        assert_eq!(rule("hgt").check("190"), Err("has no unit of cm or in".to_string()));
+        assert!(rule("hcl").check("#123abc").is_ok() && rule("hcl").check("#123abz").is_err() && rule("hcl").check("123abc").is_err());
+        assert!(rule("ecl").check("brn").is_ok() && rule("ecl").check("wat").is_err());
+        assert!(rule("pid").check("000000001").is_ok() && rule("pid").check("0123456789").is_err());
+        assert_eq!(rule("hgt").to_string(), "units 150..=193cm 59..=76in");
+    }
+
+    #[test]
+    fn lists_every_problem() {
+        let schema: Schema = PART2.parse().unwrap();
+        let passport: HashMap<String, String> = [("hgt", "190in"), ("byr", "1900"), ("iyr", "2015"), ("eyr", "2025"), ("pid", "12345678a"), ("cid", "x")]
+            .iter().map(|(key, value)| (key.to_string(), value.to_string())).collect();
+        let validation = schema.validate(&passport);
+        assert!(!validation.is_valid());
+        assert_eq!(validation.to_string(), "missing hcl, missing ecl, byr=1900 out of range 1920..=2002, hgt=190in out of range 59..=76in, pid=12345678a doesn't match /^[0-9]{9}$/");
+        assert_eq!(validation.reasons()[3], "hgt out of range 59..=76in");
+    }
+}
@@ -0,0 +1,27 @@
+use common::gen::{Generated, Rng};
+
+const SEATS: usize = 1024;
+
+/// Boarding passes for size seats (800 by default, at most 1021) with consecutive ids, except for a
+/// missing one somewhere in the middle, which is yours
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(800).clamp(2, SEATS - 3);
+    let first = rng.range(1, SEATS - size - 2);
+    let last = first + size; // one more than size, since one is missing
+    let missing = rng.range(first + 1, last - 1);
+    let mut ids: Vec<usize> = (first..=last).filter(|id| *id != missing).collect();
+    rng.shuffle(&mut ids);
+    let passes: Vec<String> = ids.iter().map(|id| boarding_pass(*id)).collect();
+    Generated::new(passes.join("\n"))
+        .answer(1, last)
+        .answer(2, missing)
+}
+
+fn boarding_pass(id: usize) -> String {
+    (0..10).rev().map(|bit| match (bit >= 3, id & (1 << bit) != 0) {
+        (true, false) => 'F',
+        (true, true) => 'B',
+        (false, false) => 'L',
+        (false, true) => 'R'
+    }).collect()
+}
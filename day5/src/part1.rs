@@ -0,0 +1,32 @@
+use common::input;
+use common::error::ParseError;
+
+fn parse_seat(line: &str) -> Result<usize,ParseError> {
+    if line.chars().count() != 10 {
+        return Err(ParseError::new(line, format!("Length was {} instead of 10", line.chars().count())));
+    }
+    let mut digit_value: usize = 2_usize.pow(10-1);
+    let mut value: usize = 0;
+    for (index, digit) in line.chars().enumerate() {
+        let (zero, one) = if index < 7 { ('F', 'B') } else { ('L', 'R') };
+        if digit == one {
+            value += digit_value;
+        } else if digit != zero {
+            return Err(ParseError::new(line, format!("Expected '{}' or '{}' but found '{}'", zero, one, digit)).at_column(index + 1));
+        }
+        digit_value /= 2;
+    }
+    Ok(value)
+}
+
+pub fn solve(text: &str) -> Result<usize, ParseError> {
+    let seats: Vec<usize> = input::parse_lines_with(text, parse_seat)?;
+    Ok(process(seats))
+}
+
+fn process(list: Vec<usize>) -> usize {
+    match list.iter().max() {
+        Some(max) => *max,
+        None => 0
+    }
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use common::gen::{Generated, Rng};
+
+/// Answers from size groups (450 by default) of up to 5 people, who share some answers within each
+/// group and add a few of their own
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(450);
+    let mut groups = Vec::new();
+    let mut anyone = 0;
+    let mut everyone = 0;
+    for _ in 0..size {
+        let common = random_questions(rng, 4);
+        let people: Vec<u32> = (0..rng.range(1, 5)).map(|_| loop {
+            let answers = common | random_questions(rng, 3);
+            if answers != 0 {
+                break answers;
+            }
+        }).collect();
+        anyone += people.iter().fold(0, |all, answers| all | answers).count_ones();
+        everyone += people.iter().fold(!0, |all, answers| all & answers).count_ones();
+        let lines: Vec<String> = people.iter().map(|answers| {
+            let mut questions: Vec<char> = (0..26).filter(|bit| answers & (1 << bit) != 0).map(|bit| (b'a' + bit as u8) as char).collect();
+            rng.shuffle(&mut questions);
+            questions.into_iter().collect()
+        }).collect();
+        groups.push(lines.join("\n"));
+    }
+    Generated::new(groups.join("\n\n"))
+        .answer(1, anyone)
+        .answer(2, everyone)
+}
+
+/// Up to count questions as a bit mask, 'a' being the lowest bit
+fn random_questions(rng: &mut Rng, count: usize) -> u32 {
+    (0..rng.range(0, count)).fold(0, |answers, _| answers | (1 << rng.below(26)))
+}
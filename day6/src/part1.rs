@@ -0,0 +1,27 @@
+use std::collections::HashSet;
+use common::input;
+use common::error::ParseError;
+
+pub fn solve(text: &str) -> Result<usize, ParseError> {
+    let groups: Vec<HashSet<char>> = input::parse_blocks_with(text, parse_group)?;
+    Ok(process(groups))
+}
+
+const ALLOWED_CHARS: [char; 26] = ['a','b','c','d','e','f','g','h','i','j','k','l','m','n','o','p','q','r','s','t','u','v','w','x','y','z'];
+fn parse_group(text: &str) -> Result<HashSet<char>, ParseError> {
+    let mut group = HashSet::<char>::new();
+    for (index, line) in input::lines(text).enumerate() {
+        for (column, c) in line.chars().enumerate() {
+            if !ALLOWED_CHARS.contains(&c) {
+                return Err(ParseError::new(line, format!("Expected a question from 'a' to 'z' but found '{}'", c))
+                    .at_column(column + 1).on_line(index + 1));
+            }
+            group.insert(c);
+        }
+    }
+    return Ok(group);
+}
+
+fn process(list: Vec<HashSet<char>>) -> usize {
+    list.iter().map(|group| group.len()).sum()
+}
\ No newline at end of file
@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+use common::input;
+use common::error::ParseError;
+
+pub fn solve(text: &str) -> Result<usize, ParseError> {
+    let groups: Vec<Vec<HashSet<char>>> = input::parse_blocks_with(text, |g| input::parse_lines_with(g, parse_person))?;
+    Ok(process(groups))
+}
+
+const ALLOWED_CHARS: [char; 26] = ['a','b','c','d','e','f','g','h','i','j','k','l','m','n','o','p','q','r','s','t','u','v','w','x','y','z'];
+fn parse_person(line: &str) -> Result<HashSet<char>, ParseError> {
+    let mut person = HashSet::<char>::new();
+    for (column, c) in line.chars().enumerate() {
+        if !ALLOWED_CHARS.contains(&c) {
+            return Err(ParseError::new(line, format!("Expected a question from 'a' to 'z' but found '{}'", c)).at_column(column + 1));
+        }
+        person.insert(c);
+    }
+    return Ok(person);
+}
+
+fn process(list: Vec<Vec<HashSet<char>>>) -> usize {
+    list.iter().map(|g| ALLOWED_CHARS.iter().filter(|c| g.iter().all(|p| p.contains(c))).count()).sum()
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+use common::gen::{Generated, Rng};
+
+const ADJECTIVES: [&str; 33] = ["bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored", "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dashed", "vivid", "vintage", "dusky", "dusty", "mellow", "pastel", "smoky", "frosted", "glossy", "matte", "rusty", "soft", "deep", "warm"];
+const COLORS: [&str; 33] = ["aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral", "crimson", "cyan", "fuchsia", "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive", "orange", "plum", "purple", "red", "salmon", "silver", "tan", "teal", "tomato", "turquoise", "violet", "white", "yellow"];
+const LAYERS: usize = 6;
+const TARGET: &str = "shiny gold";
+
+/// Rules for size colors of bag (300 by default, at most 1000) in layers, where bags only contain up
+/// to 4 bags from the layers below them, so that the number of bags inside any bag stays reasonable
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(300).clamp(LAYERS, 1000);
+    let mut names: HashSet<String> = HashSet::new();
+    names.insert(TARGET.to_string());
+    while names.len() < size {
+        names.insert(format!("{} {}", rng.pick(&ADJECTIVES), rng.pick(&COLORS)));
+    }
+    let mut bags: Vec<String> = names.into_iter().collect();
+    bags.sort(); // since a HashSet's order isn't the same for the same seed
+    rng.shuffle(&mut bags);
+    // bag i is in layer i * LAYERS / size, and contains bags from later layers
+    let layer = |i: usize| i * LAYERS / size;
+    let mut contents: Vec<Vec<(usize, usize)>> = Vec::new(); // (count, bag index) of each bag
+    for i in 0..size {
+        let first_below = (i..size).find(|j| layer(*j) > layer(i)).unwrap_or(size);
+        let mut inner: Vec<(usize, usize)> = Vec::new();
+        if first_below < size {
+            for _ in 0..rng.range(0, 4) {
+                let bag = rng.range(first_below, size - 1);
+                if inner.iter().all(|(_, b)| *b != bag) {
+                    inner.push((rng.range(1, 5), bag));
+                }
+            }
+        }
+        contents.push(inner);
+    }
+    let target = bags.iter().position(|bag| bag == TARGET).unwrap();
+    let mut lines: Vec<String> = (0..size).map(|i| rule(&bags, i, &contents[i])).collect();
+    rng.shuffle(&mut lines);
+    let holders = (0..size).filter(|i| *i != target && can_hold(&contents, *i, target)).count();
+    Generated::new(lines.join("\n"))
+        .answer(1, holders)
+        .answer(2, count_inside(&contents, target))
+}
+
+fn rule(bags: &Vec<String>, bag: usize, inner: &Vec<(usize, usize)>) -> String {
+    if inner.is_empty() {
+        return format!("{} bags contain no other bags.", bags[bag]);
+    }
+    let list: Vec<String> = inner.iter().map(|(count, b)| format!("{} {} bag{}", count, bags[*b], if *count == 1 { "" } else { "s" })).collect();
+    format!("{} bags contain {}.", bags[bag], list.join(", "))
+}
+
+fn can_hold(contents: &Vec<Vec<(usize, usize)>>, bag: usize, target: usize) -> bool {
+    contents[bag].iter().any(|(_, b)| *b == target || can_hold(contents, *b, target))
+}
+
+fn count_inside(contents: &Vec<Vec<(usize, usize)>>, bag: usize) -> usize {
+    contents[bag].iter().map(|(count, b)| count * (1 + count_inside(contents, *b))).sum()
+}
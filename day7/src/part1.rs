@@ -0,0 +1,80 @@
+use regex::Regex;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use common::input;
+use common::error::{self, Error, ParseError};
+
+struct BagRule {
+    outer_bag: String,
+    inner_bags: HashMap<String, usize>
+}
+
+impl BagRule {
+    fn contains_bag(&self, bag: &str, list: &Vec<BagRule>, depth: usize) -> Result<bool, String> {
+        if depth > list.len() {
+            return Err(format!("{} bags end up inside themselves", self.outer_bag)); // nested deeper than there are rules, so one repeats
+        }
+        if self.inner_bags.contains_key(bag) {
+            return Ok(true);
+        }
+        for inner_bag in self.inner_bags.keys() {
+            if find_rule(list, inner_bag)?.contains_bag(bag, list, depth + 1)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+pub fn solve(text: &str) -> Result<usize, Error> {
+    let rules: Vec<BagRule> = input::parse_lines_with(text, parse_rule)?;
+    Ok(process(rules, &"shiny gold")?)
+}
+
+fn find_rule<'a>(list: &'a Vec<BagRule>, bag: &str) -> Result<&'a BagRule, String> {
+    list.iter().find(|rule| rule.outer_bag == bag).ok_or(format!("No rule for {} bags", bag))
+}
+
+fn parse_rule(line: &str) -> Result<BagRule,ParseError> {
+    lazy_static! {
+        static ref NON_EMPTY_RULE: Regex = Regex::new("^([\\w ]+) bags contain (\\d+) ([\\w ]+) bags?(?:, (\\d+) ([\\w ]+) bags?)?(?:, (\\d+) ([\\w ]+) bags?)?(?:, (\\d+) ([\\w ]+) bags?)?\\.$").unwrap(); // NOTE: this will match 1-4 inner bags
+        static ref EMPTY_RULE: Regex = Regex::new("^(.+) bags contain no other bags\\.$").unwrap();
+    }
+    match NON_EMPTY_RULE.captures(line) {
+        Some(non_empty_match) => {
+            let mut groups = non_empty_match.iter();
+            groups.next(); // full match string, eg: light red bags contain 1 bright white bag, 2 muted yellow bags.
+            let outer: &str = groups.next().unwrap().unwrap().as_str();
+            let mut inners: HashMap<String, usize> = HashMap::new();
+            while let Some(m) = groups.next() {
+                let number: usize = match m {
+                    Some(s) => error::parse_part(line, s.as_str(), "a number of bags")?,
+                    None => continue
+                };
+                let bag: &str = groups.next().unwrap().unwrap().as_str();
+                inners.insert(bag.to_string(), number);
+            }
+            Ok(BagRule {
+                outer_bag: outer.to_string(),
+                inner_bags: inners
+            })
+        },
+        None => match EMPTY_RULE.captures(line) {
+            Some(empty_match) => Ok(BagRule {
+                outer_bag: empty_match.get(1).unwrap().as_str().to_string(),
+                inner_bags: HashMap::new()
+            }),
+            None => Err(ParseError::new(line, "Expected '<colour> bags contain <number> <colour> bags, ...' or '<colour> bags contain no other bags.'".to_string()))
+        }
+    }
+}
+
+fn process(list: Vec<BagRule>, target_bag: &str) -> Result<usize, String> {
+    let mut count = 0;
+    for rule in list.iter() {
+        if rule.contains_bag(target_bag, &list, 0)? {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
\ No newline at end of file
@@ -1,8 +1,8 @@
-use std::env;
-use std::fs;
 use regex::Regex;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
+use common::input;
+use common::error::{self, Error, ParseError};
 
 struct BagRule {
     outer_bag: String,
@@ -14,27 +14,29 @@ impl BagRule {
     //     self.inner_bags.contains_key(bag) || self.inner_bags.iter().any(|bag_pair| list.iter().find(|rule| rule.outer_bag == *bag_pair.0).unwrap().contains_bag(bag, list))
     // }
 
-    fn bag_count(&self, list: &Vec<BagRule>) -> usize { // includes this bag
-        1 + self.inner_bags.iter().map(|(inner_bag, inner_count)| *inner_count * list.iter().find(|rule| rule.outer_bag == *inner_bag).unwrap().bag_count(list)).sum::<usize>()
+    fn bag_count(&self, list: &Vec<BagRule>, depth: usize) -> Result<usize, String> { // includes this bag
+        if depth > list.len() {
+            return Err(format!("{} bags end up inside themselves", self.outer_bag)); // nested deeper than there are rules, so one repeats
+        }
+        let mut count: usize = 1;
+        for (inner_bag, inner_count) in self.inner_bags.iter() {
+            let inside = find_rule(list, inner_bag)?.bag_count(list, depth + 1)?;
+            count = inner_count.checked_mul(inside).and_then(|bags| count.checked_add(bags)).ok_or("Too many bags to count")?;
+        }
+        Ok(count)
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let rules: Vec<BagRule> = text.split("\r\n").map(|line| parse_rule(line)
-            .expect(&format!("Error parsing rule: {}",line))).collect();
-        let result = process(rules, &"shiny gold");
-        println!("Result: {}", result);
-    } else {
-        println!("Please provide 1 argument: Filename");
-    }
+pub fn solve(text: &str) -> Result<usize, Error> {
+    let rules: Vec<BagRule> = input::parse_lines_with(text, parse_rule)?;
+    Ok(process(rules, &"shiny gold")?)
+}
+
+fn find_rule<'a>(list: &'a Vec<BagRule>, bag: &str) -> Result<&'a BagRule, String> {
+    list.iter().find(|rule| rule.outer_bag == bag).ok_or(format!("No rule for {} bags", bag))
 }
 
-fn parse_rule(line: &str) -> Result<BagRule,String> {
+fn parse_rule(line: &str) -> Result<BagRule,ParseError> {
     lazy_static! {
         static ref NON_EMPTY_RULE: Regex = Regex::new("^([\\w ]+) bags contain (\\d+) ([\\w ]+) bags?(?:, (\\d+) ([\\w ]+) bags?)?(?:, (\\d+) ([\\w ]+) bags?)?(?:, (\\d+) ([\\w ]+) bags?)?\\.$").unwrap(); // NOTE: this will match 1-4 inner bags
         static ref EMPTY_RULE: Regex = Regex::new("^(.+) bags contain no other bags\\.$").unwrap();
@@ -45,13 +47,10 @@ fn parse_rule(line: &str) -> Result<BagRule,String> {
             groups.next(); // full match string, eg: light red bags contain 1 bright white bag, 2 muted yellow bags.
             let outer: &str = groups.next().unwrap().unwrap().as_str();
             let mut inners: HashMap<String, usize> = HashMap::new();
-            loop {
-                let number: usize = match groups.next() {
-                    Some(m) => match m {
-                        Some(s) => s.as_str().parse().unwrap(),
-                        None => continue
-                    },
-                    None => break
+            while let Some(m) = groups.next() {
+                let number: usize = match m {
+                    Some(s) => error::parse_part(line, s.as_str(), "a number of bags")?,
+                    None => continue
                 };
                 let bag: &str = groups.next().unwrap().unwrap().as_str();
                 inners.insert(bag.to_string(), number);
@@ -66,11 +65,11 @@ fn parse_rule(line: &str) -> Result<BagRule,String> {
                 outer_bag: empty_match.get(1).unwrap().as_str().to_string(),
                 inner_bags: HashMap::new()
             }),
-            None => Err("Rule didn't match regex.".to_string())
+            None => Err(ParseError::new(line, "Expected '<colour> bags contain <number> <colour> bags, ...' or '<colour> bags contain no other bags.'".to_string()))
         }
     }
 }
 
-fn process(list: Vec<BagRule>, target_bag: &str) -> usize {
-    list.iter().find(|rule| rule.outer_bag == target_bag).unwrap().bag_count(&list) - 1 // uncount target bag
+fn process(list: Vec<BagRule>, target_bag: &str) -> Result<usize, String> {
+    Ok(find_rule(&list, target_bag)?.bag_count(&list, 0)? - 1) // uncount target bag
 }
\ No newline at end of file
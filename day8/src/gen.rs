@@ -0,0 +1,29 @@
+use common::gen::{Generated, Rng};
+
+/// A boot program of size instructions (600 by default), which runs straight through except for a
+/// single jmp back to an earlier instruction, so that fixing that jmp is the only way to finish, as
+/// every nop points backwards too
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(600).max(3);
+    let jump = rng.range(1, size - 2); // not the last instruction, which is never changed
+    let mut lines = Vec::new();
+    let mut before_loop: isize = 0;
+    let mut total: isize = 0;
+    for i in 0..size {
+        if i == jump {
+            lines.push(format!("jmp -{}", rng.range(1, i)));
+        } else if i > 0 && rng.chance(1, 4) {
+            lines.push(format!("nop -{}", rng.range(1, i)));
+        } else {
+            let value = rng.range(1, 50) as isize * if rng.chance(1, 2) { 1 } else { -1 };
+            lines.push(format!("acc {:+}", value));
+            total += value;
+            if i < jump {
+                before_loop += value;
+            }
+        }
+    }
+    Generated::new(lines.join("\n"))
+        .answer(1, before_loop)
+        .answer(2, total)
+}
@@ -1,5 +1,5 @@
-use std::env;
-use std::fs;
+use common::input;
+use common::error::{self, Error, ParseError};
 
 enum InstructionType {
     NOP,
@@ -48,35 +48,28 @@ impl ExecutionState {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let instructions: Vec<Instruction> = text.split("\r\n").map(|line| parse_instruction(line)
-            .expect(&format!("Error parsing instruction: {}",line))).collect();
-        let mut state = ExecutionState {
-            instructions: instructions,
-            program_counter: 0,
-            accumulator: 0
-        };
-        let result = process(&mut state);
-        println!("Result: {}", result);
-    } else {
-        println!("Please provide 1 argument: Filename");
-    }
+pub fn solve(text: &str) -> Result<isize, Error> {
+    let instructions: Vec<Instruction> = input::parse_lines_with(text, parse_instruction)?;
+    let mut state = ExecutionState {
+        instructions: instructions,
+        program_counter: 0,
+        accumulator: 0
+    };
+    Ok(process(&mut state)?)
 }
 
-fn parse_instruction(line: &str) -> Result<Instruction,String> {
+fn parse_instruction(line: &str) -> Result<Instruction,ParseError> {
     let parts: Vec<&str> = line.split(" ").collect();
+    if parts.len() != 2 {
+        return Err(ParseError::new(line, "Expected '<command> <argument>'".to_string()));
+    }
     let com = match parts[0] {
         "nop" => InstructionType::NOP,
         "acc" => InstructionType::ACC,
         "jmp" => InstructionType::JMP,
-        _ => return Err(format!("Command not recognised: {}", parts[0]))
+        _ => return Err(ParseError::at(line, parts[0], format!("Command not recognised: {}", parts[0])))
     };
-    let arg: isize = parts[1].replace("+","").parse().expect(&format!("Argument not integer: {}", parts[1]));
+    let arg: isize = error::parse_part(line, parts[1].trim_start_matches('+'), "an integer argument")?;
     Ok(Instruction {
         command: com,
         argument: arg,
@@ -84,7 +77,13 @@ fn parse_instruction(line: &str) -> Result<Instruction,String> {
     })
 }
 
-fn process(state: &mut ExecutionState) -> isize {
-    while state.execute_step() { }
-    state.accumulator
+fn process(state: &mut ExecutionState) -> Result<isize, String> {
+    loop {
+        if state.program_counter >= state.instructions.len() {
+            return Err("Program ended without running any instruction twice".to_string());
+        }
+        if !state.execute_step() {
+            return Ok(state.accumulator);
+        }
+    }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
-use std::env;
-use std::fs;
+use common::input;
+use common::error::{self, Error, ParseError};
 
 enum InstructionType {
     NOP,
@@ -22,7 +22,9 @@ struct ExecutionState<'a> {
 
 impl<'a> ExecutionState<'a> {
     fn execute_step(&mut self) -> bool {
-        if self.visited.contains(&self.program_counter) {
+        if self.program_counter >= self.instructions.len() {
+            return false; // failed because jumped to after the end of the program
+        } else if self.visited.contains(&self.program_counter) {
             return false; // failed because command already executed
         } else {
             self.visited.insert(self.program_counter);
@@ -61,66 +63,60 @@ impl<'a> ExecutionState<'a> {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 2 {
-        let filename = &args[1];
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let mut instructions: Vec<Instruction> = text.split("\r\n").map(|line| parse_instruction(line)
-            .expect(&format!("Error parsing instruction: {}",line))).collect();
-        for i in 0..instructions.len()-1 {
-            // toggle JMP/NOP command
-            let instruction: &mut Instruction = &mut instructions[i];
-            match instruction.command {
-                InstructionType::ACC => {
-                    continue;
-                },
-                InstructionType::NOP => {
-                    instruction.command = InstructionType::JMP;
-                },
-                InstructionType::JMP => {
-                    instruction.command = InstructionType::NOP;
-                },
-            }
-            // try executing
-            match execute(&instructions) {
-                Ok(result) => {
-                    println!("Result: {}", result);
-                    break; // success
-                },
-                Err(_) => {
-                    // no luck, continue loop after undoing changes
-                }
-            }
-            // toggle back to original command
-            let instruction: &mut Instruction = &mut instructions[i];
-            match instruction.command {
-                InstructionType::ACC => {
-                    panic!();
-                },
-                InstructionType::NOP => {
-                    instruction.command = InstructionType::JMP;
-                },
-                InstructionType::JMP => {
-                    instruction.command = InstructionType::NOP;
-                },
+pub fn solve(text: &str) -> Result<isize,Error> {
+    let mut instructions: Vec<Instruction> = input::parse_lines_with(text, parse_instruction)?;
+    for i in 0..instructions.len() {
+        // toggle JMP/NOP command
+        let instruction: &mut Instruction = &mut instructions[i];
+        match instruction.command {
+            InstructionType::ACC => {
+                continue;
+            },
+            InstructionType::NOP => {
+                instruction.command = InstructionType::JMP;
+            },
+            InstructionType::JMP => {
+                instruction.command = InstructionType::NOP;
+            },
+        }
+        // try executing
+        match execute(&instructions) {
+            Ok(result) => {
+                return Ok(result); // success
+            },
+            Err(_) => {
+                // no luck, continue loop after undoing changes
             }
-        };
-    } else {
-        println!("Please provide 1 argument: Filename");
-    }
+        }
+        // toggle back to original command
+        let instruction: &mut Instruction = &mut instructions[i];
+        match instruction.command {
+            InstructionType::ACC => {
+                panic!();
+            },
+            InstructionType::NOP => {
+                instruction.command = InstructionType::JMP;
+            },
+            InstructionType::JMP => {
+                instruction.command = InstructionType::NOP;
+            },
+        }
+    };
+    Err(Error::Solve("No single JMP/NOP change lets the program complete".to_string()))
 }
 
-fn parse_instruction(line: &str) -> Result<Instruction,String> {
+fn parse_instruction(line: &str) -> Result<Instruction,ParseError> {
     let parts: Vec<&str> = line.split(" ").collect();
+    if parts.len() != 2 {
+        return Err(ParseError::new(line, "Expected '<command> <argument>'".to_string()));
+    }
     let com = match parts[0] {
         "nop" => InstructionType::NOP,
         "acc" => InstructionType::ACC,
         "jmp" => InstructionType::JMP,
-        _ => return Err(format!("Command not recognised: {}", parts[0]))
+        _ => return Err(ParseError::at(line, parts[0], format!("Command not recognised: {}", parts[0])))
     };
-    let arg: isize = parts[1].replace("+","").parse().expect(&format!("Argument not integer: {}", parts[1]));
+    let arg: isize = error::parse_part(line, parts[1].trim_start_matches('+'), "an integer argument")?;
     Ok(Instruction {
         command: com,
         argument: arg
@@ -0,0 +1,80 @@
+use common::gen::{Generated, Rng};
+
+/// XMAS data of size numbers (1000 by default) after a preamble of 5 to 25 (or longer for more than
+/// 1000 numbers), where each number is the sum of two different numbers in the window before it,
+/// except for one which is instead the sum of a contiguous run of earlier numbers (and not of any
+/// other run)
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> Generated {
+    let size = size.unwrap_or(1000).max(8);
+    let preamble = rng.range(5, 25).max(size / 40 + 1);
+    loop {
+        let mut numbers: Vec<usize> = Vec::new();
+        while numbers.len() < preamble {
+            let number = rng.range(1, 50 + preamble);
+            if !numbers.contains(&number) {
+                numbers.push(number);
+            }
+        }
+        let invalid_index = rng.range(preamble + 2, size - 1);
+        while numbers.len() < invalid_index {
+            numbers.push(next_valid(rng, &numbers, preamble));
+        }
+        let length = rng.range(2, 5.min(invalid_index));
+        let start = rng.range(0, invalid_index - length);
+        let run = &numbers[start..(start + length)];
+        let invalid: usize = run.iter().sum();
+        let answer2 = run.iter().min().unwrap() + run.iter().max().unwrap();
+        if is_sum(&numbers[(invalid_index - preamble)..], invalid) || count_runs(&numbers, invalid) != 1 {
+            continue;
+        }
+        numbers.push(invalid);
+        while numbers.len() < size {
+            numbers.push(next_valid(rng, &numbers, preamble));
+        }
+        let input = numbers.iter().map(|n| n.to_string()).collect::<Vec<String>>().join("\n");
+        return Generated::new(input)
+            .param(None, "preamble", preamble)
+            .param(Some(2), "target", invalid)
+            .answer(1, invalid)
+            .answer(2, answer2);
+    }
+}
+
+/// The sum of two different numbers from the smallest in the window, since each number is at least
+/// twice the smallest, so they double every preamble numbers even then (and the preamble is long
+/// enough for that to fit in 64 bits)
+fn next_valid(rng: &mut Rng, numbers: &Vec<usize>, preamble: usize) -> usize {
+    let mut window: Vec<usize> = numbers[(numbers.len() - preamble)..].to_vec();
+    window.sort();
+    window.dedup();
+    let smallest = &window[..(preamble / 3 + 2).min(window.len())];
+    loop {
+        let a = rng.pick(smallest);
+        let b = rng.pick(smallest);
+        if a != b {
+            return a + b;
+        }
+    }
+}
+
+fn is_sum(window: &[usize], target: usize) -> bool {
+    window.iter().any(|a| window.iter().any(|b| a != b && a + b == target))
+}
+
+/// How many runs of at least two numbers add up to target
+fn count_runs(numbers: &[usize], target: usize) -> usize {
+    let mut count = 0;
+    for start in 0..numbers.len() {
+        let mut sum = numbers[start];
+        for number in numbers[(start + 1)..].iter() {
+            sum += number;
+            if sum == target {
+                count += 1;
+            }
+            if sum >= target {
+                break;
+            }
+        }
+    }
+    count
+}
@@ -0,0 +1,14 @@
+pub mod gen;
+pub mod part1;
+pub mod part2;
+
+use common::answer::Answer;
+use common::error::Error;
+
+pub fn part1(text: &str, preamble_size: usize) -> Result<Answer, Error> {
+    Ok(part1::solve(text, &preamble_size)?.into())
+}
+
+pub fn part2(text: &str, target_sum: usize) -> Result<Answer, Error> {
+    Ok(part2::solve(text, &target_sum)?.into())
+}
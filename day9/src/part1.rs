@@ -1,33 +1,22 @@
-use std::env;
-use std::fs;
 use std::slice::Iter;
+use common::input;
+use common::error::Error;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() == 3 {
-        let filename = &args[1];
-        let preamble_size: &usize = &args[2].parse().expect("Preamble must be an integer");
-        let text = fs::read_to_string(&filename)
-            .expect(&format!("Error reading from {}", filename));
-        let data: Vec<usize> = text.split("\r\n").map(|line| line.parse()
-            .expect(&format!("Error parsing number: {}",line))).collect();
-        let result = process(&mut data.iter(), preamble_size).unwrap();
-        println!("Result: {}", result);
-    } else {
-        println!("Please provide 2 arguments: Filename, Preamble Size");
-    }
+pub fn solve(text: &str, preamble_size: &usize) -> Result<usize,Error> {
+    let data: Vec<usize> = input::parse_numbers(text)?;
+    Ok(process(&mut data.iter(), preamble_size)?)
 }
 
 fn process(data: &mut Iter<usize>, preamble_size: &usize) -> Result<usize,String> {
     let mut preamble: Vec<&usize> = Vec::new();
     // fill preamble
     for _i in 0..*preamble_size {
-        preamble.push(data.next().unwrap());
+        preamble.push(data.next().ok_or("Not enough data to fill the preamble")?);
     }
     // check data
     while let Some(value) = data.next() {
         if !check_sum(&preamble, value) {
-            return Ok(value.clone()); // found mismatched value
+            return Ok(*value); // found mismatched value
         }
         preamble.remove(0);
         preamble.push(value);
@@ -0,0 +1,28 @@
+use std::slice::Iter;
+use common::input;
+use common::error::Error;
+
+pub fn solve(text: &str, target_sum: &usize) -> Result<usize,Error> {
+    let numbers: Vec<usize> = input::parse_numbers(text)?;
+    let mut data = numbers.iter();
+    let result = process(&mut data, target_sum)?;
+    let min = result.iter().min().unwrap();
+    let max = result.iter().max().unwrap();
+    Ok(**min + **max)
+}
+
+fn process<'a>(data: &'a mut Iter<usize>, target_sum: &usize) -> Result<Vec<&'a usize>,String> {
+    let mut selection: Vec<&usize> = Vec::new();
+    let mut sum: usize = 0;
+    while !(sum == *target_sum && selection.len() > 1) {
+        if sum > *target_sum {
+            let remove_value = selection.remove(0);
+            sum -= remove_value;
+        } else {
+            let add_value = data.next().ok_or(format!("No contiguous numbers add up to {}", target_sum))?;
+            sum += add_value;
+            selection.push(add_value);
+        }
+    }
+    Ok(selection)
+}
\ No newline at end of file